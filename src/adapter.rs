@@ -1,6 +1,13 @@
 //! Non-empty adapter.
 
-use crate::non_empty::NonEmptyIterator;
+use core::{array, slice};
+
+use non_zero_size::Size;
+
+use crate::{
+    non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator},
+    slice::AsNonEmptySlice,
+};
 
 /// Adapts [`IntoIterator`] values that are known to be non-empty to implement [`NonEmptyIterator`].
 ///
@@ -35,3 +42,70 @@ impl<I: IntoIterator> IntoIterator for NonEmptyAdapter<I> {
 }
 
 unsafe impl<I: IntoIterator> NonEmptyIterator for NonEmptyAdapter<I> {}
+
+impl<I: ExactSizeIterator> ExactSizeNonEmptyIterator for NonEmptyAdapter<I> {
+    fn len(&self) -> Size {
+        let len = self.iterable.len();
+
+        // SAFETY: the caller of `new` guarantees the iterator is non-empty
+        // therefore, `len` is non-zero
+        unsafe { Size::new_unchecked(len) }
+    }
+}
+
+// SAFETY: the caller of `new` guarantees the slice is non-empty
+unsafe impl<T> AsNonEmptySlice for NonEmptyAdapter<&[T]> {
+    type Element = T;
+
+    fn as_non_empty_slice(&self) -> &[Self::Element] {
+        self.iterable
+    }
+}
+
+// SAFETY: the caller of `new` guarantees the slice is non-empty
+unsafe impl<T> AsNonEmptySlice for NonEmptyAdapter<&mut [T]> {
+    type Element = T;
+
+    fn as_non_empty_slice(&self) -> &[Self::Element] {
+        self.iterable
+    }
+}
+
+// SAFETY: the caller of `new` guarantees the iterator is non-empty
+// therefore, the remaining slice is non-empty
+unsafe impl<T> AsNonEmptySlice for NonEmptyAdapter<slice::Iter<'_, T>> {
+    type Element = T;
+
+    fn as_non_empty_slice(&self) -> &[Self::Element] {
+        self.iterable.as_slice()
+    }
+}
+
+// SAFETY: the caller of `new` guarantees the iterator is non-empty
+// therefore, the remaining slice is non-empty
+unsafe impl<T> AsNonEmptySlice for NonEmptyAdapter<slice::IterMut<'_, T>> {
+    type Element = T;
+
+    fn as_non_empty_slice(&self) -> &[Self::Element] {
+        self.iterable.as_slice()
+    }
+}
+
+// SAFETY: the caller of `new` guarantees the array is non-empty
+unsafe impl<T, const N: usize> AsNonEmptySlice for NonEmptyAdapter<[T; N]> {
+    type Element = T;
+
+    fn as_non_empty_slice(&self) -> &[Self::Element] {
+        &self.iterable
+    }
+}
+
+// SAFETY: the caller of `new` guarantees the iterator is non-empty
+// therefore, the remaining slice is non-empty
+unsafe impl<T, const N: usize> AsNonEmptySlice for NonEmptyAdapter<array::IntoIter<T, N>> {
+    type Element = T;
+
+    fn as_non_empty_slice(&self) -> &[Self::Element] {
+        self.iterable.as_slice()
+    }
+}
@@ -2,7 +2,9 @@
 
 use core::iter;
 
-use crate::non_empty::{IntoNonEmptyIterator, NonEmptyIterator};
+use non_zero_size::Size;
+
+use crate::non_empty::{ExactSizeNonEmptyIterator, IntoNonEmptyIterator, NonEmptyIterator};
 
 /// Converts the given arguments to iterators and links them together.
 ///
@@ -47,3 +49,111 @@ impl<I: NonEmptyIterator, J: Iterator<Item = I::Item>> IntoIterator for Chain<I,
 }
 
 unsafe impl<I: NonEmptyIterator, J: Iterator<Item = I::Item>> NonEmptyIterator for Chain<I, J> {}
+
+impl<I: ExactSizeNonEmptyIterator, J: ExactSizeIterator<Item = I::Item>> ExactSizeNonEmptyIterator
+    for Chain<I, J>
+{
+    /// Returns the combined length of both iterators.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the combined length overflows [`usize`].
+    fn len(&self) -> Size {
+        let len = self
+            .non_empty
+            .len()
+            .get()
+            .checked_add(self.maybe_empty.len())
+            .expect("length overflow");
+
+        // SAFETY: the non-empty length is non-zero, and the addition does not overflow
+        // therefore, `len` is non-zero
+        unsafe { Size::new_unchecked(len) }
+    }
+}
+
+/// Represents non-empty iterators that link two non-empty iterators together.
+///
+/// Unlike [`Chain`], both iterators are required to be [`NonEmptyIterator`],
+/// so that the non-emptiness holds when iterating from either end.
+///
+/// This `struct` is created by the [`chain_non_empty`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`chain_non_empty`]: NonEmptyIterator::chain_non_empty
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct ChainNonEmpty<I: NonEmptyIterator, J: NonEmptyIterator<Item = I::Item>> {
+    first: I,
+    second: J,
+}
+
+impl<I: NonEmptyIterator, J: NonEmptyIterator<Item = I::Item>> ChainNonEmpty<I, J> {
+    /// Constructs [`Self`].
+    pub const fn new(first: I, second: J) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<I: NonEmptyIterator, J: NonEmptyIterator<Item = I::Item>> IntoIterator
+    for ChainNonEmpty<I, J>
+{
+    type Item = I::Item;
+
+    type IntoIter = iter::Chain<I::IntoIter, J::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.first.into_iter().chain(self.second)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator, J: NonEmptyIterator<Item = I::Item>> NonEmptyIterator
+    for ChainNonEmpty<I, J>
+{
+}
+
+impl<I: ExactSizeNonEmptyIterator, J: ExactSizeNonEmptyIterator<Item = I::Item>>
+    ExactSizeNonEmptyIterator for ChainNonEmpty<I, J>
+{
+    /// Returns the combined length of both iterators.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the combined length overflows [`usize`].
+    fn len(&self) -> Size {
+        let len = self
+            .first
+            .len()
+            .get()
+            .checked_add(self.second.len().get())
+            .expect("length overflow");
+
+        // SAFETY: both lengths are non-zero, and the addition does not overflow
+        // therefore, `len` is non-zero
+        unsafe { Size::new_unchecked(len) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use super::chain;
+    use crate::{
+        non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator},
+        once::once,
+    };
+
+    #[test]
+    fn chain_len() {
+        assert_eq!(chain(once(1), [2, 3]).len(), Size::new(3).unwrap());
+    }
+
+    #[test]
+    fn chain_non_empty() {
+        let chained = once(1).chain_non_empty(once(2));
+
+        assert_eq!(chained.len(), Size::new(2).unwrap());
+        assert!(chained.rev().eq([2, 1]));
+    }
+}
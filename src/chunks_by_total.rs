@@ -0,0 +1,149 @@
+//! Chunking non-empty iterators by the total weight of items.
+
+use alloc::vec::Vec;
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that yield non-empty chunks of items,
+/// such that the total weight of each chunk does not exceed the maximum.
+///
+/// This `struct` is created by the [`chunks_by_total`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`chunks_by_total`]: NonEmptyIterator::chunks_by_total
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct ChunksByTotal<I: NonEmptyIterator, F: FnMut(&I::Item) -> usize> {
+    non_empty: I,
+    max: usize,
+    weight: F,
+}
+
+impl<I: NonEmptyIterator, F: FnMut(&I::Item) -> usize> ChunksByTotal<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, max: usize, weight: F) -> Self {
+        Self {
+            non_empty,
+            max,
+            weight,
+        }
+    }
+}
+
+impl<I: NonEmptyIterator, F: FnMut(&I::Item) -> usize> IntoIterator for ChunksByTotal<I, F> {
+    type Item = Vec<I::Item>;
+
+    type IntoIter = ChunksByTotalIter<I::IntoIter, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ChunksByTotalIter::new(self.non_empty.into_iter(), self.max, self.weight)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator, F: FnMut(&I::Item) -> usize> NonEmptyIterator
+    for ChunksByTotal<I, F>
+{
+}
+
+/// Represents iterators that yield non-empty chunks of items,
+/// such that the total weight of each chunk does not exceed the maximum.
+///
+/// This `struct` is created by the [`into_iter`] method on [`ChunksByTotal`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksByTotalIter<I: Iterator, F: FnMut(&I::Item) -> usize> {
+    iterator: I,
+    max: usize,
+    weight: F,
+    pending: Option<(I::Item, usize)>,
+}
+
+impl<I: Iterator, F: FnMut(&I::Item) -> usize> ChunksByTotalIter<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, max: usize, weight: F) -> Self {
+        Self {
+            iterator,
+            max,
+            weight,
+            pending: None,
+        }
+    }
+
+    fn next_weighted(&mut self) -> Option<(I::Item, usize)> {
+        self.pending.take().or_else(|| {
+            let item = self.iterator.next()?;
+
+            let weight = (self.weight)(&item);
+
+            Some((item, weight))
+        })
+    }
+}
+
+impl<I: Iterator, F: FnMut(&I::Item) -> usize> Iterator for ChunksByTotalIter<I, F> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the first item always goes into the chunk, even if its weight exceeds the maximum
+        let (item, mut total) = self.next_weighted()?;
+
+        let mut chunk = Vec::new();
+
+        chunk.push(item);
+
+        while let Some((item, weight)) = self.next_weighted() {
+            match total.checked_add(weight).filter(|&sum| sum <= self.max) {
+                Some(sum) => {
+                    total = sum;
+
+                    chunk.push(item);
+                }
+                None => {
+                    self.pending = Some((item, weight));
+
+                    break;
+                }
+            }
+        }
+
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some());
+
+        let (lower, upper) = self.iterator.size_hint();
+
+        let lower = usize::from(lower > 0 || pending > 0);
+        let upper = upper.and_then(|upper| upper.checked_add(pending));
+
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn chunks_by_total() {
+        let non_empty = [3, 4, 2, 5].try_into_non_empty_iter().unwrap();
+
+        let chunks: Vec<_> = non_empty.chunks_by_total(6, |&item| item).collect();
+
+        assert_eq!(chunks, [vec![3], vec![4, 2], vec![5]]);
+    }
+
+    #[test]
+    fn chunks_by_total_oversized() {
+        let non_empty = [1, 9, 2].try_into_non_empty_iter().unwrap();
+
+        let chunks: Vec<_> = non_empty.chunks_by_total(6, |&item| item).collect();
+
+        assert_eq!(chunks, [vec![1], vec![9], vec![2]]);
+    }
+}
@@ -0,0 +1,113 @@
+//! Chunking non-empty iterators into non-empty collections.
+
+use non_zero_size::Size;
+
+use nonempty::NonEmpty;
+
+use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
+
+/// Represents non-empty iterators that yield chunks of items as [`NonEmpty<T>`] collections.
+///
+/// This `struct` is created by the [`chunks_non_empty`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`chunks_non_empty`]: NonEmptyIterator::chunks_non_empty
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct ChunksNonEmpty<I: NonEmptyIterator> {
+    non_empty: I,
+    size: Size,
+}
+
+impl<I: NonEmptyIterator> ChunksNonEmpty<I> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, size: Size) -> Self {
+        Self { non_empty, size }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for ChunksNonEmpty<I> {
+    type Item = NonEmpty<I::Item>;
+
+    type IntoIter = ChunksNonEmptyIter<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ChunksNonEmptyIter::new(self.non_empty.into_iter(), self.size)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for ChunksNonEmpty<I> {}
+
+impl<I: ExactSizeNonEmptyIterator> ExactSizeNonEmptyIterator for ChunksNonEmpty<I> {
+    fn len(&self) -> Size {
+        let len = self.non_empty.len().get().div_ceil(self.size.get());
+
+        // SAFETY: rounding the division of non-zero values up gives non-zero values
+        unsafe { Size::new_unchecked(len) }
+    }
+}
+
+/// Represents iterators that yield chunks of items as [`NonEmpty<T>`] collections.
+///
+/// This `struct` is created by the [`into_iter`] method on [`ChunksNonEmpty`].
+///
+/// Each chunk contains exactly `size` items, except for the last one,
+/// which contains the remaining items.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksNonEmptyIter<I: Iterator> {
+    iterator: I,
+    size: Size,
+}
+
+impl<I: Iterator> ChunksNonEmptyIter<I> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, size: Size) -> Self {
+        Self { iterator, size }
+    }
+}
+
+impl<I: Iterator> Iterator for ChunksNonEmptyIter<I> {
+    type Item = NonEmpty<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let head = self.iterator.next()?;
+
+        let tail = self.iterator.by_ref().take(self.size.get() - 1).collect();
+
+        Some(NonEmpty { head, tail })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.size.get();
+
+        let (lower, upper) = self.iterator.size_hint();
+
+        (
+            lower.div_ceil(size),
+            upper.map(|upper| upper.div_ceil(size)),
+        )
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for ChunksNonEmptyIter<I> {}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn chunks_non_empty_lengths() {
+        let non_empty = [1, 2, 3, 4, 5].try_into_non_empty_iter().unwrap();
+
+        let chunks = non_empty.chunks_non_empty(Size::new(2).unwrap());
+
+        assert_eq!(chunks.len(), Size::new(3).unwrap());
+
+        assert!(chunks.map(|chunk| chunk.len()).eq([2, 2, 1]));
+    }
+}
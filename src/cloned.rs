@@ -2,7 +2,9 @@
 
 use core::iter;
 
-use crate::non_empty::NonEmptyIterator;
+use non_zero_size::Size;
+
+use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
 
 /// Represents non-empty iterators that clone the items of the underlying non-empty iterator.
 ///
@@ -42,4 +44,55 @@ where
     I: NonEmptyIterator<Item = &'a T>,
     T: Clone + 'a,
 {
+    fn count(self) -> Size {
+        self.non_empty.count()
+    }
+}
+
+impl<'a, I, T> ExactSizeNonEmptyIterator for Cloned<I>
+where
+    I: ExactSizeNonEmptyIterator<Item = &'a T>,
+    T: Clone + 'a,
+{
+    fn len(&self) -> Size {
+        self.non_empty.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use non_zero_size::Size;
+
+    use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    struct Counted<'c> {
+        clones: &'c Cell<usize>,
+    }
+
+    impl Clone for Counted<'_> {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+
+            Self {
+                clones: self.clones,
+            }
+        }
+    }
+
+    #[test]
+    fn cloned_len() {
+        let clones = Cell::new(0);
+
+        let items = [Counted { clones: &clones }, Counted { clones: &clones }];
+
+        let cloned = items.iter().try_into_non_empty_iter().unwrap().cloned();
+
+        assert_eq!(cloned.len(), Size::new(2).unwrap());
+        assert_eq!(clones.get(), 0);
+
+        assert_eq!(cloned.count(), Size::new(2).unwrap());
+        assert_eq!(clones.get(), 0);
+    }
 }
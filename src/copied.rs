@@ -2,7 +2,9 @@
 
 use core::iter;
 
-use crate::non_empty::NonEmptyIterator;
+use non_zero_size::Size;
+
+use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
 
 /// Represents non-empty iterators that copy the items of the underlying non-empty iterator.
 ///
@@ -42,4 +44,34 @@ where
     I: NonEmptyIterator<Item = &'a T>,
     T: Copy + 'a,
 {
+    fn count(self) -> Size {
+        self.non_empty.count()
+    }
+}
+
+impl<'a, I, T> ExactSizeNonEmptyIterator for Copied<I>
+where
+    I: ExactSizeNonEmptyIterator<Item = &'a T>,
+    T: Copy + 'a,
+{
+    fn len(&self) -> Size {
+        self.non_empty.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn copied_len() {
+        let items = [1, 2, 3];
+
+        let copied = items.iter().try_into_non_empty_iter().unwrap().copied();
+
+        assert_eq!(copied.len(), Size::new(3).unwrap());
+        assert_eq!(copied.count(), Size::new(3).unwrap());
+    }
 }
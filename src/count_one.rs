@@ -0,0 +1,46 @@
+//! Counting items of non-empty iterators via summation.
+
+use core::iter::Sum;
+
+use non_zero_size::Size;
+
+/// Represents single units that sum into counts.
+///
+/// Summing into [`Option<Size>`] yields [`None`] for empty iterators, and the non-zero
+/// count otherwise. The count saturates at [`usize::MAX`].
+///
+/// This is used by the [`sum_count`] method on [`NonEmptyIterator`].
+///
+/// [`sum_count`]: crate::non_empty::NonEmptyIterator::sum_count
+/// [`NonEmptyIterator`]: crate::non_empty::NonEmptyIterator
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CountOne;
+
+impl Sum<CountOne> for Option<Size> {
+    fn sum<I: Iterator<Item = CountOne>>(iterator: I) -> Self {
+        let count = iterator.fold(0usize, |count, _| count.saturating_add(1));
+
+        // SAFETY: `count` is checked to be non-zero
+        (count > 0).then(|| unsafe { Size::new_unchecked(count) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::iter;
+
+    use non_zero_size::Size;
+
+    use super::CountOne;
+
+    #[test]
+    fn count_one_sum() {
+        let count: Option<Size> = iter::repeat_n(CountOne, 4).sum();
+
+        assert_eq!(count, Size::new(4));
+
+        let count: Option<Size> = iter::empty::<CountOne>().sum();
+
+        assert_eq!(count, None);
+    }
+}
@@ -1,8 +1,10 @@
-//! Repeating non-empty iterators endlessly.
+//! Repeating non-empty iterators endlessly or the given number of times.
 
 use core::iter;
 
-use crate::non_empty::NonEmptyIterator;
+use non_zero_size::Size;
+
+use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
 
 /// Represents non-empty iterators that repeat endlessly.
 ///
@@ -41,3 +43,163 @@ where
 }
 
 unsafe impl<I: NonEmptyIterator> NonEmptyIterator for Cycle<I> where I::IntoIter: Clone {}
+
+/// Represents non-empty iterators that repeat the given number of times.
+///
+/// This `struct` is created by the [`cycle_n`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`cycle_n`]: NonEmptyIterator::cycle_n
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct CycleN<I: NonEmptyIterator>
+where
+    I::IntoIter: Clone,
+{
+    non_empty: I,
+    count: Size,
+}
+
+impl<I: NonEmptyIterator> CycleN<I>
+where
+    I::IntoIter: Clone,
+{
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, count: Size) -> Self {
+        Self { non_empty, count }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for CycleN<I>
+where
+    I::IntoIter: Clone,
+{
+    type Item = I::Item;
+
+    type IntoIter = CycleNIter<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CycleNIter::new(self.non_empty.into_iter(), self.count)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for CycleN<I> where I::IntoIter: Clone {}
+
+impl<I: ExactSizeNonEmptyIterator> ExactSizeNonEmptyIterator for CycleN<I>
+where
+    I::IntoIter: Clone,
+{
+    /// Returns the length of the underlying iterator multiplied by the count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total length overflows [`usize`].
+    fn len(&self) -> Size {
+        let len = self
+            .non_empty
+            .len()
+            .get()
+            .checked_mul(self.count.get())
+            .expect("length overflow");
+
+        // SAFETY: the product of non-zero values does not overflow, therefore `len` is non-zero
+        unsafe { Size::new_unchecked(len) }
+    }
+}
+
+/// Represents iterators that repeat the given number of times.
+///
+/// This `struct` is created by the [`into_iter`] method on [`CycleN`].
+///
+/// The underlying iterator is cloned once less than the count,
+/// as the last repetition uses the original iterator.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CycleNIter<I: Iterator + Clone> {
+    iterator: I,
+    original: Option<I>,
+    remaining: usize,
+}
+
+impl<I: Iterator + Clone> CycleNIter<I> {
+    /// Constructs [`Self`].
+    pub fn new(iterator: I, count: Size) -> Self {
+        let remaining = count.get() - 1;
+
+        let original = (remaining > 0).then(|| iterator.clone());
+
+        Self {
+            iterator,
+            original,
+            remaining,
+        }
+    }
+}
+
+impl<I: Iterator + Clone> Iterator for CycleNIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.iterator.next() {
+            return Some(item);
+        }
+
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+
+        self.iterator = if self.remaining == 0 {
+            self.original.take()?
+        } else {
+            self.original.clone()?
+        };
+
+        self.iterator.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iterator.size_hint();
+
+        let Some(original) = self.original.as_ref() else {
+            return (lower, upper);
+        };
+
+        let (original_lower, original_upper) = original.size_hint();
+
+        let lower = original_lower
+            .saturating_mul(self.remaining)
+            .saturating_add(lower);
+
+        let upper = upper
+            .zip(original_upper)
+            .and_then(|(upper, original_upper)| {
+                original_upper
+                    .checked_mul(self.remaining)?
+                    .checked_add(upper)
+            });
+
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::{
+        non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator},
+        repeat::repeat_n,
+    };
+
+    #[test]
+    fn cycle_n_len() {
+        let cycled = repeat_n(0, Size::new(3).unwrap()).cycle_n(Size::new(4).unwrap());
+
+        assert_eq!(cycled.len(), Size::new(12).unwrap());
+        assert_eq!(cycled.count(), Size::new(12).unwrap());
+    }
+}
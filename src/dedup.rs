@@ -0,0 +1,103 @@
+//! Removing consecutive duplicate items of non-empty iterators.
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that remove consecutive duplicate items.
+///
+/// This `struct` is created by the [`dedup`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`dedup`]: NonEmptyIterator::dedup
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Dedup<I: NonEmptyIterator>
+where
+    I::Item: PartialEq,
+{
+    non_empty: I,
+}
+
+impl<I: NonEmptyIterator> Dedup<I>
+where
+    I::Item: PartialEq,
+{
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I) -> Self {
+        Self { non_empty }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for Dedup<I>
+where
+    I::Item: PartialEq,
+{
+    type Item = I::Item;
+
+    type IntoIter = DedupIter<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DedupIter::new(self.non_empty.into_iter())
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for Dedup<I> where I::Item: PartialEq {}
+
+/// Represents iterators that remove consecutive duplicate items.
+///
+/// This `struct` is created by the [`into_iter`] method on [`Dedup`].
+///
+/// Only the next distinct item is buffered, so no allocation is needed.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupIter<I: Iterator>
+where
+    I::Item: PartialEq,
+{
+    iterator: I,
+    next: Option<I::Item>,
+}
+
+impl<I: Iterator> DedupIter<I>
+where
+    I::Item: PartialEq,
+{
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I) -> Self {
+        Self {
+            iterator,
+            next: None,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for DedupIter<I>
+where
+    I::Item: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = match self.next.take() {
+            Some(item) => item,
+            None => self.iterator.next()?,
+        };
+
+        // skip the duplicates, buffering the first distinct item
+        self.next = self.iterator.find(|item| *item != current);
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let next = usize::from(self.next.is_some());
+
+        let (lower, upper) = self.iterator.size_hint();
+
+        let lower = usize::from(lower > 0 || next > 0);
+        let upper = upper.and_then(|upper| upper.checked_add(next));
+
+        (lower, upper)
+    }
+}
@@ -2,7 +2,9 @@
 
 use core::iter;
 
-use crate::non_empty::NonEmptyIterator;
+use non_zero_size::Size;
+
+use crate::non_empty::{DoubleEndedNonEmptyIterator, ExactSizeNonEmptyIterator, NonEmptyIterator};
 
 /// Represents non-empty iterators that yield the current count and the item during iteration.
 ///
@@ -23,6 +25,26 @@ impl<I: NonEmptyIterator> Enumerate<I> {
     }
 }
 
+impl<I: ExactSizeNonEmptyIterator + DoubleEndedNonEmptyIterator> Enumerate<I> {
+    /// Consumes the non-empty iterator from the back, returning the last item paired
+    /// with its index along with the possibly empty iterator over the remaining ones.
+    ///
+    /// The index of the last item is computed from the exact length of the non-empty iterator,
+    /// so this does not require the underlying iterator to be [`ExactSizeIterator`].
+    /// For instance, this works for [`Chain`], even though [`consume_back`] on
+    /// [`DoubleEndedNonEmptyIterator`] is not available for its enumeration.
+    ///
+    /// [`consume_back`]: DoubleEndedNonEmptyIterator::consume_back
+    /// [`Chain`]: crate::chain::Chain
+    pub fn consume_back(self) -> ((usize, I::Item), iter::Enumerate<I::IntoIter>) {
+        let index = self.non_empty.len().get() - 1;
+
+        let (item, rest) = self.non_empty.consume_back();
+
+        ((index, item), rest.enumerate())
+    }
+}
+
 impl<I: NonEmptyIterator> IntoIterator for Enumerate<I> {
     type Item = (usize, I::Item);
 
@@ -34,3 +56,22 @@ impl<I: NonEmptyIterator> IntoIterator for Enumerate<I> {
 }
 
 unsafe impl<I: NonEmptyIterator> NonEmptyIterator for Enumerate<I> {}
+
+impl<I: ExactSizeNonEmptyIterator> ExactSizeNonEmptyIterator for Enumerate<I> {
+    fn len(&self) -> Size {
+        self.non_empty.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{chain::chain, non_empty::NonEmptyIterator, once::once};
+
+    #[test]
+    fn enumerate_consume_back() {
+        let ((index, item), rest) = chain(once('a'), ['b', 'c']).enumerate().consume_back();
+
+        assert_eq!((index, item), (2, 'c'));
+        assert!(rest.eq([(0, 'a'), (1, 'b')]));
+    }
+}
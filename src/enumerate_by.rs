@@ -0,0 +1,118 @@
+//! Enumerating items in non-empty iterators with custom starts and steps.
+
+use non_zero_size::Size;
+
+use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
+
+/// Represents non-empty iterators that yield the current index, starting from the given one
+/// and advancing by the given step, and the item during iteration.
+///
+/// This `struct` is created by the [`enumerate_by`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`enumerate_by`]: NonEmptyIterator::enumerate_by
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct EnumerateBy<I: NonEmptyIterator> {
+    non_empty: I,
+    start: Size,
+    step: Size,
+}
+
+impl<I: NonEmptyIterator> EnumerateBy<I> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, start: Size, step: Size) -> Self {
+        Self {
+            non_empty,
+            start,
+            step,
+        }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for EnumerateBy<I> {
+    type Item = (Size, I::Item);
+
+    type IntoIter = EnumerateByIter<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        EnumerateByIter::new(self.non_empty.into_iter(), self.start, self.step)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for EnumerateBy<I> {}
+
+impl<I: ExactSizeNonEmptyIterator> ExactSizeNonEmptyIterator for EnumerateBy<I> {
+    fn len(&self) -> Size {
+        self.non_empty.len()
+    }
+}
+
+/// Represents iterators that yield the current index, starting from the given one
+/// and advancing by the given step, and the item during iteration.
+///
+/// This `struct` is created by the [`into_iter`] method on [`EnumerateBy`].
+///
+/// # Panics
+///
+/// Yielding the item with the index exceeding [`usize::MAX`] panics.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EnumerateByIter<I: Iterator> {
+    iterator: I,
+    index: Option<Size>,
+    step: Size,
+}
+
+impl<I: Iterator> EnumerateByIter<I> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, start: Size, step: Size) -> Self {
+        Self {
+            iterator,
+            index: Some(start),
+            step,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for EnumerateByIter<I> {
+    type Item = (Size, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next()?;
+
+        let index = self.index.expect("index overflow");
+
+        self.index = index.get().checked_add(self.step.get()).map(|next| {
+            // SAFETY: `next` is the sum of non-zero values without overflowing,
+            // therefore it is non-zero
+            unsafe { Size::new_unchecked(next) }
+        });
+
+        Some((index, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn enumerate_by_start_step() {
+        let non_empty = ['a', 'b', 'c'].try_into_non_empty_iter().unwrap();
+
+        let enumerated = non_empty
+            .enumerate_by(Size::new(10).unwrap(), Size::new(5).unwrap())
+            .map(|(index, item)| (index.get(), item));
+
+        assert!(enumerated.eq([(10, 'a'), (15, 'b'), (20, 'c')]));
+    }
+}
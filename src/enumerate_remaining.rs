@@ -0,0 +1,120 @@
+//! Enumerating items in non-empty iterators with the remaining counts.
+
+use non_zero_size::Size;
+
+use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
+
+/// Represents non-empty iterators that yield the number of remaining items,
+/// including the current one, and the item during iteration.
+///
+/// This `struct` is created by the [`enumerate_remaining`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`enumerate_remaining`]: NonEmptyIterator::enumerate_remaining
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct EnumerateRemaining<I: ExactSizeNonEmptyIterator> {
+    non_empty: I,
+}
+
+impl<I: ExactSizeNonEmptyIterator> EnumerateRemaining<I> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I) -> Self {
+        Self { non_empty }
+    }
+}
+
+impl<I: ExactSizeNonEmptyIterator> IntoIterator for EnumerateRemaining<I> {
+    type Item = (Size, I::Item);
+
+    type IntoIter = EnumerateRemainingIter<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let remaining = self.non_empty.len();
+
+        EnumerateRemainingIter::new(self.non_empty.into_iter(), remaining)
+    }
+}
+
+unsafe impl<I: ExactSizeNonEmptyIterator> NonEmptyIterator for EnumerateRemaining<I> {}
+
+impl<I: ExactSizeNonEmptyIterator> ExactSizeNonEmptyIterator for EnumerateRemaining<I> {
+    fn len(&self) -> Size {
+        self.non_empty.len()
+    }
+}
+
+/// Represents iterators that yield the number of remaining items,
+/// including the current one, and the item during iteration.
+///
+/// This `struct` is created by the [`into_iter`] method on [`EnumerateRemaining`].
+///
+/// The count never goes below one, even if the underlying iterator
+/// yields more items than it has reported.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EnumerateRemainingIter<I: Iterator> {
+    iterator: I,
+    remaining: Size,
+}
+
+impl<I: Iterator> EnumerateRemainingIter<I> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, remaining: Size) -> Self {
+        Self {
+            iterator,
+            remaining,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for EnumerateRemainingIter<I> {
+    type Item = (Size, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next()?;
+
+        let remaining = self.remaining;
+
+        let next = remaining.get().saturating_sub(1).max(1);
+
+        // SAFETY: `next` is clamped to be at least one, therefore it is non-zero
+        self.remaining = unsafe { Size::new_unchecked(next) };
+
+        Some((remaining, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::{
+        non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator},
+        repeat::repeat_n,
+    };
+
+    #[test]
+    fn enumerate_remaining_counts_down() {
+        let non_empty = ['a', 'b', 'c'].try_into_non_empty_iter().unwrap();
+
+        let remaining = non_empty
+            .enumerate_remaining()
+            .map(|(remaining, item)| (remaining.get(), item));
+
+        assert!(remaining.eq([(3, 'a'), (2, 'b'), (1, 'c')]));
+    }
+
+    #[test]
+    fn enumerate_remaining_single() {
+        let remaining = repeat_n('a', Size::MIN).enumerate_remaining();
+
+        assert!(remaining.eq([(Size::MIN, 'a')]));
+    }
+}
@@ -0,0 +1,107 @@
+//! Enumerating items in non-empty iterators with non-zero indices.
+
+use non_zero_size::Size;
+
+use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
+
+/// Represents non-empty iterators that yield the current index, starting from one,
+/// and the item during iteration.
+///
+/// This `struct` is created by the [`enumerate_size`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`enumerate_size`]: NonEmptyIterator::enumerate_size
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct EnumerateSize<I: NonEmptyIterator> {
+    non_empty: I,
+}
+
+impl<I: NonEmptyIterator> EnumerateSize<I> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I) -> Self {
+        Self { non_empty }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for EnumerateSize<I> {
+    type Item = (Size, I::Item);
+
+    type IntoIter = EnumerateSizeIter<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        EnumerateSizeIter::new(self.non_empty.into_iter())
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for EnumerateSize<I> {}
+
+impl<I: ExactSizeNonEmptyIterator> ExactSizeNonEmptyIterator for EnumerateSize<I> {
+    fn len(&self) -> Size {
+        self.non_empty.len()
+    }
+}
+
+/// Represents iterators that yield the current index, starting from one,
+/// and the item during iteration.
+///
+/// This `struct` is created by the [`into_iter`] method on [`EnumerateSize`].
+///
+/// # Panics
+///
+/// Iterating over more than [`usize::MAX`] items panics, as the index would overflow.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EnumerateSizeIter<I: Iterator> {
+    iterator: I,
+    count: usize,
+}
+
+impl<I: Iterator> EnumerateSizeIter<I> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I) -> Self {
+        Self { iterator, count: 0 }
+    }
+}
+
+impl<I: Iterator> Iterator for EnumerateSizeIter<I> {
+    type Item = (Size, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next()?;
+
+        self.count = self.count.checked_add(1).expect("index overflow");
+
+        // SAFETY: `count` starts from zero and is incremented without overflowing,
+        // therefore it is non-zero
+        let index = unsafe { Size::new_unchecked(self.count) };
+
+        Some((index, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn enumerate_size() {
+        let non_empty = ['a', 'b', 'c'].try_into_non_empty_iter().unwrap();
+
+        let enumerated = non_empty.enumerate_size().into_iter();
+
+        assert!(enumerated.eq([
+            (Size::new(1).unwrap(), 'a'),
+            (Size::new(2).unwrap(), 'b'),
+            (Size::new(3).unwrap(), 'c'),
+        ]));
+    }
+}
@@ -1,6 +1,6 @@
 //! Mapping non-empty iterators' items to non-empty iterators that have their items yielded.
 
-use core::iter;
+use core::{fmt, iter};
 
 use crate::non_empty::{IntoNonEmptyIterator, NonEmptyIterator};
 
@@ -44,3 +44,207 @@ unsafe impl<I: NonEmptyIterator, J: IntoNonEmptyIterator, F: FnMut(I::Item) -> J
     for FlatMap<I, J, F>
 {
 }
+
+/// Represents non-empty iterators which map items to non-empty iterators that have
+/// their items yielded, allowing to observe the boundaries between them.
+///
+/// This `struct` is created by the [`flat_map_non_empty`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`flat_map_non_empty`]: NonEmptyIterator::flat_map_non_empty
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct FlatMapNonEmpty<I: NonEmptyIterator, J: IntoNonEmptyIterator, F: FnMut(I::Item) -> J> {
+    non_empty: I,
+    function: F,
+}
+
+impl<I: NonEmptyIterator, J: IntoNonEmptyIterator, F: FnMut(I::Item) -> J>
+    FlatMapNonEmpty<I, J, F>
+{
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, function: F) -> Self {
+        Self {
+            non_empty,
+            function,
+        }
+    }
+}
+
+impl<I: NonEmptyIterator, J: IntoNonEmptyIterator, F: FnMut(I::Item) -> J> IntoIterator
+    for FlatMapNonEmpty<I, J, F>
+{
+    type Item = J::Item;
+
+    type IntoIter = FlatMapNonEmptyIter<I::IntoIter, J, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FlatMapNonEmptyIter::new(self.non_empty.into_iter(), self.function)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator, J: IntoNonEmptyIterator, F: FnMut(I::Item) -> J> NonEmptyIterator
+    for FlatMapNonEmpty<I, J, F>
+{
+}
+
+/// Represents the rest of the current group in [`FlatMapNonEmptyIter`].
+pub type Group<J> = <<J as IntoNonEmptyIterator>::IntoNonEmptyIter as IntoIterator>::IntoIter;
+
+/// Represents iterators which map items to non-empty iterators, called groups,
+/// that have their items yielded, allowing to observe the boundaries between them.
+///
+/// This `struct` is created by the [`into_iter`] method on [`FlatMapNonEmpty`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FlatMapNonEmptyIter<I: Iterator, J: IntoNonEmptyIterator, F: FnMut(I::Item) -> J> {
+    iterator: I,
+    function: F,
+    group: Option<Group<J>>,
+    starts_group: bool,
+}
+
+impl<I: Iterator + fmt::Debug, J: IntoNonEmptyIterator, F: FnMut(I::Item) -> J + fmt::Debug>
+    fmt::Debug for FlatMapNonEmptyIter<I, J, F>
+where
+    Group<J>: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("FlatMapNonEmptyIter")
+            .field("iterator", &self.iterator)
+            .field("function", &self.function)
+            .field("group", &self.group)
+            .field("starts_group", &self.starts_group)
+            .finish()
+    }
+}
+
+impl<I: Iterator + Clone, J: IntoNonEmptyIterator, F: FnMut(I::Item) -> J + Clone> Clone
+    for FlatMapNonEmptyIter<I, J, F>
+where
+    Group<J>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iterator: self.iterator.clone(),
+            function: self.function.clone(),
+            group: self.group.clone(),
+            starts_group: self.starts_group,
+        }
+    }
+}
+
+impl<I: Iterator, J: IntoNonEmptyIterator, F: FnMut(I::Item) -> J> FlatMapNonEmptyIter<I, J, F> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, function: F) -> Self {
+        Self {
+            iterator,
+            function,
+            group: None,
+            starts_group: false,
+        }
+    }
+
+    /// Checks whether the last yielded item is the first item of its group.
+    ///
+    /// Returns [`false`] if no items were yielded yet.
+    #[must_use]
+    pub const fn starts_group(&self) -> bool {
+        self.starts_group
+    }
+
+    /// Returns the immutable reference to the rest of the current group, if any.
+    pub const fn group(&self) -> Option<&Group<J>> {
+        self.group.as_ref()
+    }
+
+    /// Returns the mutable reference to the rest of the current group, if any.
+    pub const fn group_mut(&mut self) -> Option<&mut Group<J>> {
+        self.group.as_mut()
+    }
+}
+
+impl<I: Iterator, J: IntoNonEmptyIterator, F: FnMut(I::Item) -> J> Iterator
+    for FlatMapNonEmptyIter<I, J, F>
+{
+    type Item = J::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.group.as_mut().and_then(Iterator::next) {
+            self.starts_group = false;
+
+            return Some(item);
+        }
+
+        let (item, rest) = (self.function)(self.iterator.next()?)
+            .into_non_empty_iter()
+            .consume();
+
+        self.group = Some(rest);
+        self.starts_group = true;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iterator.size_hint();
+
+        let (group_lower, group_upper) = self
+            .group
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint);
+
+        // each remaining group yields at least one item
+        let lower = lower.saturating_add(group_lower);
+
+        let upper = match (upper, group_upper) {
+            (Some(0), group_upper) => group_upper,
+            _ => None,
+        };
+
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::{
+        non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator},
+        repeat::repeat_n,
+    };
+
+    #[test]
+    fn flat_map_non_empty_groups() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let mut iterator = non_empty
+            .flat_map_non_empty(|count| repeat_n(count, Size::new(count).unwrap()))
+            .into_iter();
+
+        let mut items = [(0, false); 6];
+
+        for slot in &mut items {
+            let item = iterator.next().unwrap();
+
+            *slot = (item, iterator.starts_group());
+        }
+
+        assert_eq!(
+            items,
+            [
+                (1, true),
+                (2, true),
+                (2, false),
+                (3, true),
+                (3, false),
+                (3, false),
+            ]
+        );
+
+        assert_eq!(iterator.next(), None);
+    }
+}
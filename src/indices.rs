@@ -0,0 +1,133 @@
+//! Non-empty iterators over non-zero indices.
+
+use non_zero_size::Size;
+
+use crate::{non_empty::NonEmptyIterator, zip::Zip};
+
+/// Creates [`Indices`] non-empty iterator that yields indices starting from one.
+pub const fn indices() -> Indices {
+    Indices::new()
+}
+
+/// Represents non-empty iterators that yield indices starting from one.
+///
+/// The indices are yielded up to [`usize::MAX`], which makes these iterators
+/// practically endless.
+///
+/// This `struct` is created by the [`indices`] function. See its documentation for more.
+#[derive(Debug, Clone, Copy, Default)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Indices;
+
+impl Indices {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl IntoIterator for Indices {
+    type Item = Size;
+
+    type IntoIter = IndicesIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IndicesIter::new()
+    }
+}
+
+unsafe impl NonEmptyIterator for Indices {}
+
+/// Represents non-empty iterators that yield the index, starting from one,
+/// along with the item of the underlying non-empty iterator.
+///
+/// This type is returned by the [`zip_indices`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`zip_indices`]: NonEmptyIterator::zip_indices
+pub type ZipIndices<I> = Zip<Indices, I>;
+
+/// Represents iterators that yield indices starting from one.
+///
+/// This `struct` is created by the [`into_iter`] method on [`Indices`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IndicesIter {
+    index: Option<Size>,
+}
+
+impl IndicesIter {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        // SAFETY: one is non-zero
+        let index = unsafe { Size::new_unchecked(1) };
+
+        Self { index: Some(index) }
+    }
+}
+
+impl Default for IndicesIter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for IndicesIter {
+    type Item = Size;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index?;
+
+        // SAFETY: the incremented index is greater than one, therefore it is non-zero
+        self.index = index
+            .get()
+            .checked_add(1)
+            .map(|next| unsafe { Size::new_unchecked(next) });
+
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.index.map_or(0, |index| usize::MAX - index.get() + 1);
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for IndicesIter {}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::{format, vec::Vec};
+
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn zip_indices_map() {
+        let non_empty = ['a', 'b', 'c'].try_into_non_empty_iter().unwrap();
+
+        let formatted: Vec<_> = non_empty
+            .zip_indices()
+            .map(|(index, item)| format!("{index}: {item}"))
+            .collect();
+
+        assert_eq!(formatted.join(", "), "1: a, 2: b, 3: c");
+    }
+
+    #[test]
+    fn zip_indices_size_hint() {
+        let non_empty = ['a', 'b', 'c'].try_into_non_empty_iter().unwrap();
+
+        let mut zipped = non_empty.zip_indices().into_iter();
+
+        assert_eq!(zipped.size_hint(), (3, Some(3)));
+
+        zipped.next();
+
+        assert_eq!(zipped.size_hint(), (2, Some(2)));
+    }
+}
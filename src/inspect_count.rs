@@ -0,0 +1,123 @@
+//! Inspecting item counts of non-empty iterators.
+
+use non_zero_size::Size;
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that count items as they are yielded,
+/// calling the function with the total count once the iteration finishes.
+///
+/// This `struct` is created by the [`inspect_count`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`inspect_count`]: NonEmptyIterator::inspect_count
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct InspectCount<I: NonEmptyIterator, F: FnOnce(Size)> {
+    non_empty: I,
+    function: F,
+}
+
+impl<I: NonEmptyIterator, F: FnOnce(Size)> InspectCount<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, function: F) -> Self {
+        Self {
+            non_empty,
+            function,
+        }
+    }
+}
+
+impl<I: NonEmptyIterator, F: FnOnce(Size)> IntoIterator for InspectCount<I, F> {
+    type Item = I::Item;
+
+    type IntoIter = InspectCountIter<I::IntoIter, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        InspectCountIter::new(self.non_empty, self.function)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator, F: FnOnce(Size)> NonEmptyIterator for InspectCount<I, F> {}
+
+/// Represents iterators that count items as they are yielded,
+/// calling the function with the total count once the iteration finishes.
+///
+/// This `struct` is created by the [`into_iter`] method on [`InspectCount`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InspectCountIter<I: Iterator, F: FnOnce(Size)> {
+    iterator: I,
+    count: usize,
+    function: Option<F>,
+}
+
+impl<I: Iterator, F: FnOnce(Size)> InspectCountIter<I, F> {
+    /// Constructs [`Self`].
+    ///
+    /// Note that this function accepts non-empty iterators only,
+    /// as the total count passed to the function is required to be non-zero.
+    pub fn new<N: NonEmptyIterator<IntoIter = I>>(non_empty: N, function: F) -> Self {
+        Self {
+            iterator: non_empty.into_iter(),
+            count: 0,
+            function: Some(function),
+        }
+    }
+}
+
+impl<I: Iterator, F: FnOnce(Size)> Iterator for InspectCountIter<I, F> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(item) = self.iterator.next() else {
+            if let Some(function) = self.function.take() {
+                // SAFETY: the iterator was constructed from some non-empty iterator,
+                // and the count saturates instead of wrapping, so it is non-zero
+                let count = unsafe { Size::new_unchecked(self.count) };
+
+                function(count);
+            }
+
+            return None;
+        };
+
+        self.count = self.count.saturating_add(1);
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use non_zero_size::Size;
+
+    use crate::{chain::chain, non_empty::NonEmptyIterator, once::once};
+
+    #[test]
+    fn inspect_count() {
+        let calls = Cell::new(0);
+        let total = Cell::new(None);
+
+        let mut iterator = chain(once(1), [2, 3])
+            .inspect_count(|count| {
+                calls.set(calls.get() + 1);
+                total.set(Some(count));
+            })
+            .into_iter();
+
+        assert!(iterator.by_ref().eq([1, 2, 3]));
+        assert_eq!(iterator.next(), None);
+
+        assert_eq!(calls.get(), 1);
+        assert_eq!(total.get(), Size::new(3));
+    }
+}
@@ -0,0 +1,108 @@
+//! Inspecting errors in non-empty iterators over results.
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that allow inspecting each error before yielding it.
+///
+/// This `struct` is created by the [`inspect_err`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`inspect_err`]: NonEmptyIterator::inspect_err
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct InspectErr<I: NonEmptyIterator, F> {
+    non_empty: I,
+    function: F,
+}
+
+impl<I: NonEmptyIterator, F> InspectErr<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, function: F) -> Self {
+        Self {
+            non_empty,
+            function,
+        }
+    }
+}
+
+impl<T, E, I: NonEmptyIterator<Item = Result<T, E>>, F: FnMut(&E)> IntoIterator
+    for InspectErr<I, F>
+{
+    type Item = I::Item;
+
+    type IntoIter = InspectErrIter<I::IntoIter, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        InspectErrIter::new(self.non_empty.into_iter(), self.function)
+    }
+}
+
+unsafe impl<T, E, I: NonEmptyIterator<Item = Result<T, E>>, F: FnMut(&E)> NonEmptyIterator
+    for InspectErr<I, F>
+{
+}
+
+/// Represents iterators that allow inspecting each error before yielding it.
+///
+/// This `struct` is created by the [`into_iter`] method on [`InspectErr`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InspectErrIter<I: Iterator, F> {
+    iterator: I,
+    function: F,
+}
+
+impl<I: Iterator, F> InspectErrIter<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, function: F) -> Self {
+        Self { iterator, function }
+    }
+}
+
+impl<T, E, I: Iterator<Item = Result<T, E>>, F: FnMut(&E)> Iterator for InspectErrIter<I, F> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iterator
+            .next()
+            .map(|item| item.inspect_err(&mut self.function))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<T, E, I: DoubleEndedIterator<Item = Result<T, E>>, F: FnMut(&E)> DoubleEndedIterator
+    for InspectErrIter<I, F>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iterator
+            .next_back()
+            .map(|item| item.inspect_err(&mut self.function))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn inspect_err_each_error() {
+        let errors = Cell::new(0);
+
+        let results: [Result<i32, &str>; 3] = [Ok(1), Err("a"), Err("b")];
+
+        let inspected = results
+            .try_into_non_empty_iter()
+            .unwrap()
+            .inspect_err(|_| errors.set(errors.get() + 1));
+
+        assert!(inspected.eq([Ok(1), Err("a"), Err("b")]));
+        assert_eq!(errors.get(), 2);
+    }
+}
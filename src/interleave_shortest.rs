@@ -0,0 +1,142 @@
+//! Alternating items of two non-empty iterators until either of them is exhausted.
+
+use non_zero_size::Size;
+
+use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
+
+/// Represents non-empty iterators that alternate between the items of two non-empty
+/// iterators, yielding them in pairs until either of the iterators is exhausted.
+///
+/// This `struct` is created by the [`interleave_shortest`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`interleave_shortest`]: NonEmptyIterator::interleave_shortest
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct InterleaveShortest<I: NonEmptyIterator, J: NonEmptyIterator<Item = I::Item>> {
+    first: I,
+    second: J,
+}
+
+impl<I: NonEmptyIterator, J: NonEmptyIterator<Item = I::Item>> InterleaveShortest<I, J> {
+    /// Constructs [`Self`].
+    pub const fn new(first: I, second: J) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<I: NonEmptyIterator, J: NonEmptyIterator<Item = I::Item>> IntoIterator
+    for InterleaveShortest<I, J>
+{
+    type Item = I::Item;
+
+    type IntoIter = InterleaveShortestIter<I::IntoIter, J::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        InterleaveShortestIter::new(self.first.into_iter(), self.second.into_iter())
+    }
+}
+
+unsafe impl<I: NonEmptyIterator, J: NonEmptyIterator<Item = I::Item>> NonEmptyIterator
+    for InterleaveShortest<I, J>
+{
+}
+
+impl<I: ExactSizeNonEmptyIterator, J: ExactSizeNonEmptyIterator<Item = I::Item>>
+    ExactSizeNonEmptyIterator for InterleaveShortest<I, J>
+{
+    fn len(&self) -> Size {
+        let len = self
+            .first
+            .len()
+            .min(self.second.len())
+            .get()
+            .saturating_mul(2);
+
+        // SAFETY: the product of non-zero values saturates, therefore `len` is non-zero
+        unsafe { Size::new_unchecked(len) }
+    }
+}
+
+/// Represents iterators that alternate between the items of two iterators,
+/// yielding them in pairs until either of the iterators is exhausted.
+///
+/// This `struct` is created by the [`into_iter`] method on [`InterleaveShortest`].
+///
+/// Both iterators are advanced before yielding the item of the first one,
+/// so that the item is only yielded if the second iterator has the item to pair it with.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InterleaveShortestIter<I: Iterator, J: Iterator<Item = I::Item>> {
+    first: I,
+    second: J,
+    pending: Option<I::Item>,
+}
+
+impl<I: Iterator, J: Iterator<Item = I::Item>> InterleaveShortestIter<I, J> {
+    /// Constructs [`Self`].
+    pub const fn new(first: I, second: J) -> Self {
+        Self {
+            first,
+            second,
+            pending: None,
+        }
+    }
+}
+
+impl<I: Iterator, J: Iterator<Item = I::Item>> Iterator for InterleaveShortestIter<I, J> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.pending.take() {
+            return Some(item);
+        }
+
+        let item = self.first.next()?;
+
+        self.pending = Some(self.second.next()?);
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some());
+
+        let (first_lower, first_upper) = self.first.size_hint();
+        let (second_lower, second_upper) = self.second.size_hint();
+
+        let lower = first_lower
+            .min(second_lower)
+            .saturating_mul(2)
+            .saturating_add(pending);
+
+        let upper = match (first_upper, second_upper) {
+            (Some(first), Some(second)) => Some(first.min(second)),
+            (Some(upper), None) | (None, Some(upper)) => Some(upper),
+            (None, None) => None,
+        }
+        .and_then(|upper| upper.checked_mul(2)?.checked_add(pending));
+
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn interleave_shortest_pairs() {
+        let odd = [1, 3, 5].try_into_non_empty_iter().unwrap();
+        let even = [2, 4].try_into_non_empty_iter().unwrap();
+
+        let interleaved = odd.interleave_shortest(even);
+
+        assert_eq!(interleaved.len(), Size::new(4).unwrap());
+        assert!(interleaved.eq([1, 2, 3, 4]));
+    }
+}
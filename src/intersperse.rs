@@ -0,0 +1,395 @@
+//! Placing separators between adjacent items of non-empty iterators.
+
+use core::{fmt, iter::Peekable};
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that place the separator between adjacent items.
+///
+/// This `struct` is created by the [`intersperse`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// # Double-ended
+///
+/// If the underlying iterator is [`DoubleEndedIterator`], so is the iterator returned
+/// from [`into_iter`], placing separators between the reversed items as well.
+///
+/// [`intersperse`]: NonEmptyIterator::intersperse
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Intersperse<I: NonEmptyIterator>
+where
+    I::Item: Clone,
+{
+    non_empty: I,
+    separator: I::Item,
+}
+
+impl<I: NonEmptyIterator> Intersperse<I>
+where
+    I::Item: Clone,
+{
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, separator: I::Item) -> Self {
+        Self {
+            non_empty,
+            separator,
+        }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for Intersperse<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    type IntoIter = IntersperseIter<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntersperseIter::new(self.non_empty.into_iter(), self.separator)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for Intersperse<I> where I::Item: Clone {}
+
+/// Represents iterators that place the separator between adjacent items.
+///
+/// This `struct` is created by the [`into_iter`] method on [`Intersperse`].
+///
+/// The separator is only cloned when there is some item to place it before,
+/// so it is cloned exactly once less than the number of items.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntersperseIter<I: Iterator>
+where
+    I::Item: Clone,
+{
+    iterator: Peekable<I>,
+    separator: I::Item,
+    separate_front: bool,
+    separate_back: bool,
+}
+
+impl<I: Iterator> IntersperseIter<I>
+where
+    I::Item: Clone,
+{
+    /// Constructs [`Self`].
+    pub fn new(iterator: I, separator: I::Item) -> Self {
+        Self {
+            iterator: iterator.peekable(),
+            separator,
+            separate_front: false,
+            separate_back: false,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for IntersperseIter<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        step(
+            &mut self.iterator,
+            Iterator::next,
+            &mut self.separate_front,
+            &mut self.separate_back,
+            || self.separator.clone(),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(&self.iterator, self.separate_front, self.separate_back)
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for IntersperseIter<I>
+where
+    I::Item: Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        step(
+            &mut self.iterator,
+            DoubleEndedIterator::next_back,
+            &mut self.separate_back,
+            &mut self.separate_front,
+            || self.separator.clone(),
+        )
+    }
+}
+
+/// Represents non-empty iterators that place separators computed from the given function
+/// between adjacent items.
+///
+/// This `struct` is created by the [`intersperse_with`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// # Double-ended
+///
+/// If the underlying iterator is [`DoubleEndedIterator`], so is the iterator returned
+/// from [`into_iter`], placing separators between the reversed items as well.
+///
+/// [`intersperse_with`]: NonEmptyIterator::intersperse_with
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct IntersperseWith<I: NonEmptyIterator, F: FnMut() -> I::Item> {
+    non_empty: I,
+    function: F,
+}
+
+impl<I: NonEmptyIterator, F: FnMut() -> I::Item> IntersperseWith<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, function: F) -> Self {
+        Self {
+            non_empty,
+            function,
+        }
+    }
+}
+
+impl<I: NonEmptyIterator, F: FnMut() -> I::Item> IntoIterator for IntersperseWith<I, F> {
+    type Item = I::Item;
+
+    type IntoIter = IntersperseWithIter<I::IntoIter, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntersperseWithIter::new(self.non_empty.into_iter(), self.function)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator, F: FnMut() -> I::Item> NonEmptyIterator for IntersperseWith<I, F> {}
+
+/// Represents iterators that place separators computed from the given function
+/// between adjacent items.
+///
+/// This `struct` is created by the [`into_iter`] method on [`IntersperseWith`].
+///
+/// The function is only called when there is some item to place the separator before,
+/// so it is called exactly once less than the number of items.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntersperseWithIter<I: Iterator, F: FnMut() -> I::Item> {
+    iterator: Peekable<I>,
+    function: F,
+    separate_front: bool,
+    separate_back: bool,
+}
+
+impl<I: Iterator + fmt::Debug, F: FnMut() -> I::Item + fmt::Debug> fmt::Debug
+    for IntersperseWithIter<I, F>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("IntersperseWithIter")
+            .field("iterator", &self.iterator)
+            .field("function", &self.function)
+            .field("separate_front", &self.separate_front)
+            .field("separate_back", &self.separate_back)
+            .finish()
+    }
+}
+
+impl<I: Iterator + Clone, F: FnMut() -> I::Item + Clone> Clone for IntersperseWithIter<I, F>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iterator: self.iterator.clone(),
+            function: self.function.clone(),
+            separate_front: self.separate_front,
+            separate_back: self.separate_back,
+        }
+    }
+}
+
+impl<I: Iterator, F: FnMut() -> I::Item> IntersperseWithIter<I, F> {
+    /// Constructs [`Self`].
+    pub fn new(iterator: I, function: F) -> Self {
+        Self {
+            iterator: iterator.peekable(),
+            function,
+            separate_front: false,
+            separate_back: false,
+        }
+    }
+}
+
+impl<I: Iterator, F: FnMut() -> I::Item> Iterator for IntersperseWithIter<I, F> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        step(
+            &mut self.iterator,
+            Iterator::next,
+            &mut self.separate_front,
+            &mut self.separate_back,
+            &mut self.function,
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(&self.iterator, self.separate_front, self.separate_back)
+    }
+}
+
+impl<I: DoubleEndedIterator, F: FnMut() -> I::Item> DoubleEndedIterator
+    for IntersperseWithIter<I, F>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        step(
+            &mut self.iterator,
+            DoubleEndedIterator::next_back,
+            &mut self.separate_back,
+            &mut self.separate_front,
+            &mut self.function,
+        )
+    }
+}
+
+/// Advances from one end, where `separate` is set if the last item yielded from this end
+/// is yet to be followed by the separator, and `separate_other` is the same for the other end.
+fn step<I: Iterator, N: FnOnce(&mut Peekable<I>) -> Option<I::Item>, S: FnOnce() -> I::Item>(
+    iterator: &mut Peekable<I>,
+    next: N,
+    separate: &mut bool,
+    separate_other: &mut bool,
+    separator: S,
+) -> Option<I::Item> {
+    if !*separate {
+        let item = next(iterator)?;
+
+        *separate = true;
+
+        return Some(item);
+    }
+
+    if iterator.peek().is_none() {
+        // the separator is needed only if some item was yielded from the other end
+        if !*separate_other {
+            return None;
+        }
+
+        *separate_other = false;
+    }
+
+    *separate = false;
+
+    Some(separator())
+}
+
+fn size_hint<I: Iterator>(
+    iterator: &Peekable<I>,
+    separate_front: bool,
+    separate_back: bool,
+) -> (usize, Option<usize>) {
+    let (lower, upper) = iterator.size_hint();
+
+    let lower = total(lower, separate_front, separate_back).unwrap_or(usize::MAX);
+    let upper = upper.and_then(|upper| total(upper, separate_front, separate_back));
+
+    (lower, upper)
+}
+
+fn total(items: usize, separate_front: bool, separate_back: bool) -> Option<usize> {
+    let pending = usize::from(separate_front) + usize::from(separate_back);
+
+    match items.checked_sub(1) {
+        // the only separator left is the one between the items yielded from both ends
+        None => Some(usize::from(separate_front && separate_back)),
+        Some(separators) => items.checked_add(separators)?.checked_add(pending),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    struct Counted<'c> {
+        clones: &'c Cell<usize>,
+    }
+
+    impl Clone for Counted<'_> {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+
+            Self {
+                clones: self.clones,
+            }
+        }
+    }
+
+    #[test]
+    fn intersperse_rev() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let iterator = non_empty.intersperse(0).into_iter().rev();
+
+        assert!(iterator.eq([3, 0, 2, 0, 1]));
+    }
+
+    #[test]
+    fn intersperse_both_ends() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let mut iterator = non_empty.intersperse(0).into_iter();
+
+        assert_eq!(iterator.next(), Some(1));
+        assert_eq!(iterator.next_back(), Some(3));
+        assert_eq!(iterator.next(), Some(0));
+        assert_eq!(iterator.next_back(), Some(0));
+        assert_eq!(iterator.next(), Some(2));
+        assert_eq!(iterator.next_back(), None);
+    }
+
+    #[test]
+    fn intersperse_clones() {
+        let clones = Cell::new(0);
+
+        let counted = || Counted { clones: &clones };
+
+        let non_empty = [counted(), counted(), counted(), counted()]
+            .try_into_non_empty_iter()
+            .unwrap();
+
+        let mut iterator = non_empty.intersperse(counted()).into_iter();
+
+        assert_eq!(iterator.by_ref().count(), 7);
+        assert!(iterator.next().is_none());
+
+        assert_eq!(clones.get(), 3);
+    }
+
+    #[test]
+    fn intersperse_with_calls() {
+        let calls = Cell::new(0);
+
+        let non_empty = [1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        let mut iterator = non_empty
+            .intersperse_with(|| {
+                calls.set(calls.get() + 1);
+
+                0
+            })
+            .into_iter();
+
+        assert!(iterator.by_ref().eq([1, 0, 2, 0, 3, 0, 4]));
+        assert_eq!(iterator.next(), None);
+
+        assert_eq!(calls.get(), 3);
+    }
+}
@@ -0,0 +1,100 @@
+//! Non-empty iterators that endlessly apply the function to the preceding item.
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Creates [`Iterate<T, F>`] non-empty iterator which, starting from the initial item,
+/// endlessly computes each successive item from the preceding one.
+pub const fn iterate<T: Clone, F: FnMut(&T) -> T>(initial: T, function: F) -> Iterate<T, F> {
+    Iterate::new(initial, function)
+}
+
+/// Represents non-empty iterators which, starting from the initial item,
+/// endlessly compute each successive item from the preceding one.
+///
+/// This `struct` is created by the [`iterate`] function. See its documentation for more.
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Iterate<T: Clone, F: FnMut(&T) -> T> {
+    initial: T,
+    function: F,
+}
+
+impl<T: Clone, F: FnMut(&T) -> T> Iterate<T, F> {
+    /// Constructs [`Self`].
+    pub const fn new(initial: T, function: F) -> Self {
+        Self { initial, function }
+    }
+}
+
+impl<T: Clone, F: FnMut(&T) -> T> IntoIterator for Iterate<T, F> {
+    type Item = T;
+
+    type IntoIter = IterateIter<T, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterateIter::new(self.initial, self.function)
+    }
+}
+
+unsafe impl<T: Clone, F: FnMut(&T) -> T> NonEmptyIterator for Iterate<T, F> {}
+
+/// Represents iterators which, starting from the initial item,
+/// endlessly compute each successive item from the preceding one.
+///
+/// This `struct` is created by the [`into_iter`] method on [`Iterate`].
+///
+/// The function is only called when the next item is requested,
+/// and each yielded item is cloned from the current one.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IterateIter<T: Clone, F: FnMut(&T) -> T> {
+    current: T,
+    function: F,
+    started: bool,
+}
+
+impl<T: Clone, F: FnMut(&T) -> T> IterateIter<T, F> {
+    /// Constructs [`Self`].
+    pub const fn new(initial: T, function: F) -> Self {
+        Self {
+            current: initial,
+            function,
+            started: false,
+        }
+    }
+}
+
+impl<T: Clone, F: FnMut(&T) -> T> Iterator for IterateIter<T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.started {
+            self.current = (self.function)(&self.current);
+        } else {
+            self.started = true;
+        }
+
+        Some(self.current.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use super::iterate;
+    use crate::non_empty::NonEmptyIterator;
+
+    #[test]
+    fn iterate_doubling() {
+        let doubled = iterate(1, |item| item * 2).take(Size::new(4).unwrap());
+
+        assert!(doubled.eq([1, 2, 4, 8]));
+    }
+}
@@ -3,6 +3,7 @@
 #![deny(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -11,62 +12,177 @@ pub mod non_empty;
 
 pub mod adapter;
 pub mod chain;
+#[cfg(feature = "alloc")]
+pub mod chunks_by_total;
+#[cfg(feature = "nonempty")]
+pub mod chunks_non_empty;
 pub mod cloned;
 pub mod copied;
+pub mod count_one;
 pub mod cycle;
+pub mod dedup;
 pub mod enumerate;
+pub mod enumerate_by;
+pub mod enumerate_remaining;
+pub mod enumerate_size;
 pub mod flat_map;
 pub mod flatten;
 pub mod fuse;
+pub mod indices;
 pub mod inspect;
+pub mod inspect_count;
+pub mod inspect_err;
+pub mod interleave_shortest;
+pub mod intersperse;
+pub mod iterate;
 pub mod map;
+pub mod map_first;
+pub mod map_last;
+pub mod map_result;
+pub mod map_while_inclusive;
+pub mod min_max;
 pub mod once;
+pub mod pad_to;
 pub mod peeked;
+#[cfg(feature = "alloc")]
+pub mod put_back;
+pub mod reduce_segments;
 pub mod repeat;
 pub mod rev;
+#[cfg(feature = "alloc")]
+pub mod rotate_left;
+pub mod running;
+pub mod saturating;
+#[cfg(feature = "alloc")]
+pub mod skip_last;
+pub mod slice;
+#[cfg(feature = "alloc")]
+pub mod sorted_stream;
 pub mod step_by;
 pub mod successors;
 pub mod take;
+#[cfg(feature = "alloc")]
+pub mod take_last;
+pub mod unfold;
+pub mod windowed_fold;
+#[cfg(feature = "alloc")]
+pub mod windows_exact;
 pub mod zip;
+pub mod zip_with_next;
 
 #[doc(inline)]
 pub use non_empty::{
-    FromNonEmptyIterator, IntoNonEmptyIterator, NonEmptyIterator, TryIntoNonEmptyIterator,
+    DoubleEndedNonEmptyIterator, ExactSizeNonEmptyIterator, FromNonEmptyIterator,
+    IntoNonEmptyIterator, NonEmptyIterator, TryIntoNonEmptyIterator,
 };
 
 #[doc(inline)]
 pub use adapter::NonEmptyAdapter;
 #[doc(inline)]
-pub use chain::{Chain, chain};
+pub use chain::{Chain, ChainNonEmpty, chain};
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use chunks_by_total::ChunksByTotal;
+#[cfg(feature = "nonempty")]
+#[doc(inline)]
+pub use chunks_non_empty::ChunksNonEmpty;
 #[doc(inline)]
 pub use cloned::Cloned;
 #[doc(inline)]
-pub use cycle::Cycle;
+pub use count_one::CountOne;
+#[doc(inline)]
+pub use cycle::{Cycle, CycleN};
+#[doc(inline)]
+pub use dedup::Dedup;
 #[doc(inline)]
 pub use enumerate::Enumerate;
 #[doc(inline)]
-pub use flat_map::FlatMap;
+pub use enumerate_by::EnumerateBy;
+#[doc(inline)]
+pub use enumerate_remaining::EnumerateRemaining;
+#[doc(inline)]
+pub use enumerate_size::EnumerateSize;
+#[doc(inline)]
+pub use flat_map::{FlatMap, FlatMapNonEmpty};
 #[doc(inline)]
 pub use flatten::Flatten;
 #[doc(inline)]
 pub use fuse::Fuse;
 #[doc(inline)]
+pub use indices::{Indices, ZipIndices, indices};
+#[doc(inline)]
 pub use inspect::Inspect;
 #[doc(inline)]
+pub use inspect_count::InspectCount;
+#[doc(inline)]
+pub use inspect_err::InspectErr;
+#[doc(inline)]
+pub use interleave_shortest::InterleaveShortest;
+#[doc(inline)]
+pub use intersperse::{Intersperse, IntersperseWith};
+#[doc(inline)]
+pub use iterate::{Iterate, iterate};
+#[doc(inline)]
 pub use map::Map;
 #[doc(inline)]
+pub use map_first::MapFirst;
+#[doc(inline)]
+pub use map_last::MapLast;
+#[doc(inline)]
+pub use map_result::{MapErr, MapOk};
+#[doc(inline)]
+pub use map_while_inclusive::MapWhileInclusive;
+#[doc(inline)]
+pub use min_max::MinMaxResult;
+#[doc(inline)]
 pub use once::{Once, OnceWith, once, once_with};
 #[doc(inline)]
+pub use pad_to::PadTo;
+#[doc(inline)]
 pub use peeked::Peeked;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use put_back::PutBack;
+#[doc(inline)]
+pub use reduce_segments::ReduceSegments;
 #[doc(inline)]
-pub use repeat::{Repeat, RepeatN, RepeatWith, repeat, repeat_n, repeat_with};
+pub use repeat::{
+    Repeat, RepeatN, RepeatWith, RepeatWithN, repeat, repeat_n, repeat_with, repeat_with_n,
+};
 #[doc(inline)]
 pub use rev::Rev;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use rotate_left::RotateLeft;
+#[doc(inline)]
+pub use running::{RunningMax, RunningMin};
+#[doc(inline)]
+pub use saturating::SaturatingAdd;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use skip_last::SkipLast;
+#[doc(inline)]
+pub use slice::AsNonEmptySlice;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use sorted_stream::SortedStream;
 #[doc(inline)]
 pub use step_by::StepBy;
 #[doc(inline)]
 pub use successors::{Successors, successors};
 #[doc(inline)]
 pub use take::Take;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use take_last::TakeLast;
+#[doc(inline)]
+pub use unfold::{Unfold, unfold};
+#[doc(inline)]
+pub use windowed_fold::WindowedFold;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use windows_exact::WindowsExact;
 #[doc(inline)]
 pub use zip::{Zip, zip};
+#[doc(inline)]
+pub use zip_with_next::ZipWithNext;
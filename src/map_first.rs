@@ -0,0 +1,60 @@
+//! Mapping the first item of non-empty iterators.
+
+use core::iter;
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that map the first item of the non-empty iterator
+/// with the function, yielding the rest of the items unchanged.
+///
+/// This `struct` is created by the [`map_first`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`map_first`]: NonEmptyIterator::map_first
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct MapFirst<I: NonEmptyIterator, F: FnOnce(I::Item) -> I::Item> {
+    non_empty: I,
+    function: F,
+}
+
+impl<I: NonEmptyIterator, F: FnOnce(I::Item) -> I::Item> MapFirst<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, function: F) -> Self {
+        Self {
+            non_empty,
+            function,
+        }
+    }
+}
+
+impl<I: NonEmptyIterator, F: FnOnce(I::Item) -> I::Item> IntoIterator for MapFirst<I, F> {
+    type Item = I::Item;
+
+    type IntoIter = iter::Chain<iter::Once<I::Item>, I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (item, rest) = self.non_empty.consume();
+
+        iter::once((self.function)(item)).chain(rest)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator, F: FnOnce(I::Item) -> I::Item> NonEmptyIterator
+    for MapFirst<I, F>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn map_first() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let mapped = non_empty.map_first(|item| item * 10).into_iter();
+
+        assert!(mapped.eq([10, 2, 3]));
+    }
+}
@@ -0,0 +1,158 @@
+//! Mapping the last item of non-empty iterators.
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that map the last item of the non-empty iterator
+/// with the function, yielding the rest of the items unchanged.
+///
+/// This `struct` is created by the [`map_last`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// # Double-ended
+///
+/// If the underlying iterator is [`DoubleEndedIterator`], so is the iterator returned
+/// from [`into_iter`], mapping the first item yielded from the back.
+///
+/// [`map_last`]: NonEmptyIterator::map_last
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct MapLast<I: NonEmptyIterator, F: FnOnce(I::Item) -> I::Item> {
+    non_empty: I,
+    function: F,
+}
+
+impl<I: NonEmptyIterator, F: FnOnce(I::Item) -> I::Item> MapLast<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, function: F) -> Self {
+        Self {
+            non_empty,
+            function,
+        }
+    }
+}
+
+impl<I: NonEmptyIterator, F: FnOnce(I::Item) -> I::Item> IntoIterator for MapLast<I, F> {
+    type Item = I::Item;
+
+    type IntoIter = MapLastIter<I::IntoIter, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MapLastIter::new(self.non_empty.into_iter(), self.function)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator, F: FnOnce(I::Item) -> I::Item> NonEmptyIterator for MapLast<I, F> {}
+
+/// Represents iterators that map the last item with the function,
+/// yielding the rest of the items unchanged.
+///
+/// This `struct` is created by the [`into_iter`] method on [`MapLast`].
+///
+/// In order to know whether the item is the last one, the next item is buffered in advance
+/// when iterating from the front.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapLastIter<I: Iterator, F: FnOnce(I::Item) -> I::Item> {
+    iterator: I,
+    peeked: Option<I::Item>,
+    function: Option<F>,
+}
+
+impl<I: Iterator, F: FnOnce(I::Item) -> I::Item> MapLastIter<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, function: F) -> Self {
+        Self {
+            iterator,
+            peeked: None,
+            function: Some(function),
+        }
+    }
+}
+
+impl<I: Iterator, F: FnOnce(I::Item) -> I::Item> Iterator for MapLastIter<I, F> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.peeked.take() {
+            Some(item) => item,
+            None => self.iterator.next()?,
+        };
+
+        // once the function is used, there is no need to look ahead anymore
+        if self.function.is_none() {
+            return Some(item);
+        }
+
+        self.peeked = self.iterator.next();
+
+        if self.peeked.is_some() {
+            return Some(item);
+        }
+
+        self.function.take().map(|function| function(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = usize::from(self.peeked.is_some());
+
+        let (lower, upper) = self.iterator.size_hint();
+
+        let lower = lower.saturating_add(peeked);
+        let upper = upper.and_then(|upper| upper.checked_add(peeked));
+
+        (lower, upper)
+    }
+}
+
+impl<I: DoubleEndedIterator, F: FnOnce(I::Item) -> I::Item> DoubleEndedIterator
+    for MapLastIter<I, F>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // the item buffered from the front is the only one left if the iterator is exhausted
+        let item = match self.iterator.next_back() {
+            Some(item) => item,
+            None => self.peeked.take()?,
+        };
+
+        match self.function.take() {
+            Some(function) => Some(function(item)),
+            None => Some(item),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator},
+        once::once,
+    };
+
+    #[test]
+    fn map_last() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let mapped = non_empty.map_last(|item| item * 10).into_iter();
+
+        assert!(mapped.eq([1, 2, 30]));
+    }
+
+    #[test]
+    fn map_last_single() {
+        let mapped = once(1).map_last(|item| item * 10).into_iter();
+
+        assert!(mapped.eq([10]));
+    }
+
+    #[test]
+    fn map_last_rev() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let mapped = non_empty.map_last(|item| item * 10).into_iter().rev();
+
+        assert!(mapped.eq([30, 2, 1]));
+    }
+}
@@ -0,0 +1,199 @@
+//! Mapping either variant of results in non-empty iterators.
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators over results that map each success value
+/// with the function, leaving errors unchanged.
+///
+/// This `struct` is created by the [`map_ok`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`map_ok`]: NonEmptyIterator::map_ok
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct MapOk<I: NonEmptyIterator, F> {
+    non_empty: I,
+    function: F,
+}
+
+impl<I: NonEmptyIterator, F> MapOk<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, function: F) -> Self {
+        Self {
+            non_empty,
+            function,
+        }
+    }
+}
+
+impl<T, U, E, I: NonEmptyIterator<Item = Result<T, E>>, F: FnMut(T) -> U> IntoIterator
+    for MapOk<I, F>
+{
+    type Item = Result<U, E>;
+
+    type IntoIter = MapOkIter<I::IntoIter, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MapOkIter::new(self.non_empty.into_iter(), self.function)
+    }
+}
+
+unsafe impl<T, U, E, I: NonEmptyIterator<Item = Result<T, E>>, F: FnMut(T) -> U> NonEmptyIterator
+    for MapOk<I, F>
+{
+}
+
+/// Represents iterators over results that map each success value with the function,
+/// leaving errors unchanged.
+///
+/// This `struct` is created by the [`into_iter`] method on [`MapOk`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapOkIter<I: Iterator, F> {
+    iterator: I,
+    function: F,
+}
+
+impl<I: Iterator, F> MapOkIter<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, function: F) -> Self {
+        Self { iterator, function }
+    }
+}
+
+impl<T, U, E, I: Iterator<Item = Result<T, E>>, F: FnMut(T) -> U> Iterator for MapOkIter<I, F> {
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iterator
+            .next()
+            .map(|item| item.map(&mut self.function))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<T, U, E, I: DoubleEndedIterator<Item = Result<T, E>>, F: FnMut(T) -> U> DoubleEndedIterator
+    for MapOkIter<I, F>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iterator
+            .next_back()
+            .map(|item| item.map(&mut self.function))
+    }
+}
+
+/// Represents non-empty iterators over results that map each error with the function,
+/// leaving success values unchanged.
+///
+/// This `struct` is created by the [`map_err`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`map_err`]: NonEmptyIterator::map_err
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct MapErr<I: NonEmptyIterator, F> {
+    non_empty: I,
+    function: F,
+}
+
+impl<I: NonEmptyIterator, F> MapErr<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, function: F) -> Self {
+        Self {
+            non_empty,
+            function,
+        }
+    }
+}
+
+impl<T, E, R, I: NonEmptyIterator<Item = Result<T, E>>, F: FnMut(E) -> R> IntoIterator
+    for MapErr<I, F>
+{
+    type Item = Result<T, R>;
+
+    type IntoIter = MapErrIter<I::IntoIter, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MapErrIter::new(self.non_empty.into_iter(), self.function)
+    }
+}
+
+unsafe impl<T, E, R, I: NonEmptyIterator<Item = Result<T, E>>, F: FnMut(E) -> R> NonEmptyIterator
+    for MapErr<I, F>
+{
+}
+
+/// Represents iterators over results that map each error with the function,
+/// leaving success values unchanged.
+///
+/// This `struct` is created by the [`into_iter`] method on [`MapErr`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapErrIter<I: Iterator, F> {
+    iterator: I,
+    function: F,
+}
+
+impl<I: Iterator, F> MapErrIter<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, function: F) -> Self {
+        Self { iterator, function }
+    }
+}
+
+impl<T, E, R, I: Iterator<Item = Result<T, E>>, F: FnMut(E) -> R> Iterator for MapErrIter<I, F> {
+    type Item = Result<T, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iterator
+            .next()
+            .map(|item| item.map_err(&mut self.function))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<T, E, R, I: DoubleEndedIterator<Item = Result<T, E>>, F: FnMut(E) -> R> DoubleEndedIterator
+    for MapErrIter<I, F>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iterator
+            .next_back()
+            .map(|item| item.map_err(&mut self.function))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn map_ok() {
+        let results: [Result<i32, &str>; 3] = [Ok(1), Err("e"), Ok(3)];
+
+        let mapped = results
+            .try_into_non_empty_iter()
+            .unwrap()
+            .map_ok(|item| item + 1);
+
+        assert!(mapped.eq([Ok(2), Err("e"), Ok(4)]));
+    }
+
+    #[test]
+    fn map_err() {
+        let results: [Result<i32, &str>; 3] = [Ok(1), Err("e"), Ok(3)];
+
+        let mapped = results.try_into_non_empty_iter().unwrap().map_err(str::len);
+
+        assert!(mapped.eq([Ok(1), Err(1), Ok(3)]));
+    }
+}
@@ -0,0 +1,120 @@
+//! Mapping items of non-empty iterators while the function allows, including the last one.
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that map items with the function until it signals to stop,
+/// yielding the mapped item that caused the stop as well.
+///
+/// This `struct` is created by the [`map_while_inclusive`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`map_while_inclusive`]: NonEmptyIterator::map_while_inclusive
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct MapWhileInclusive<I: NonEmptyIterator, F> {
+    non_empty: I,
+    function: F,
+}
+
+impl<I: NonEmptyIterator, F> MapWhileInclusive<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, function: F) -> Self {
+        Self {
+            non_empty,
+            function,
+        }
+    }
+}
+
+impl<T, I: NonEmptyIterator, F: FnMut(I::Item) -> (T, bool)> IntoIterator
+    for MapWhileInclusive<I, F>
+{
+    type Item = T;
+
+    type IntoIter = MapWhileInclusiveIter<I::IntoIter, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MapWhileInclusiveIter::new(self.non_empty.into_iter(), self.function)
+    }
+}
+
+unsafe impl<T, I: NonEmptyIterator, F: FnMut(I::Item) -> (T, bool)> NonEmptyIterator
+    for MapWhileInclusive<I, F>
+{
+}
+
+/// Represents iterators that map items with the function until it signals to stop,
+/// yielding the mapped item that caused the stop as well.
+///
+/// This `struct` is created by the [`into_iter`] method on [`MapWhileInclusive`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapWhileInclusiveIter<I: Iterator, F> {
+    iterator: I,
+    function: F,
+    done: bool,
+}
+
+impl<I: Iterator, F> MapWhileInclusiveIter<I, F> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, function: F) -> Self {
+        Self {
+            iterator,
+            function,
+            done: false,
+        }
+    }
+}
+
+impl<T, I: Iterator, F: FnMut(I::Item) -> (T, bool)> Iterator for MapWhileInclusiveIter<I, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let item = self.iterator.next()?;
+
+        let (output, keep_going) = (self.function)(item);
+
+        self.done = !keep_going;
+
+        Some(output)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.iterator.size_hint();
+
+        (lower.min(1), upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn map_while_inclusive_terminator() {
+        let non_empty = [1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        let mapped = non_empty.map_while_inclusive(|item| (item * 10, item < 3));
+
+        assert!(mapped.eq([10, 20, 30]));
+    }
+
+    #[test]
+    fn map_while_inclusive_first() {
+        let non_empty = [1, 2].try_into_non_empty_iter().unwrap();
+
+        let mapped = non_empty.map_while_inclusive(|item| (item, false));
+
+        assert!(mapped.eq([1]));
+    }
+}
@@ -0,0 +1,37 @@
+//! Minimum and maximum items of non-empty iterators.
+
+/// Represents the minimum and maximum items of non-empty iterators.
+///
+/// This `enum` is returned by the [`min_max_eq`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// Note that there is no variant for empty iterators, since they can not be non-empty.
+///
+/// [`min_max_eq`]: crate::non_empty::NonEmptyIterator::min_max_eq
+/// [`NonEmptyIterator`]: crate::non_empty::NonEmptyIterator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MinMaxResult<T> {
+    /// All items are equal, the first one is contained.
+    OneElement(T),
+    /// Not all items are equal, the minimum and maximum items are contained, respectively.
+    MinMax(T, T),
+}
+
+impl<T> MinMaxResult<T> {
+    /// Checks whether all items were equal.
+    pub const fn is_one_element(&self) -> bool {
+        matches!(self, Self::OneElement(_))
+    }
+
+    /// Returns the minimum and maximum items, cloning the item if all items were equal.
+    #[must_use]
+    pub fn into_min_max(self) -> (T, T)
+    where
+        T: Clone,
+    {
+        match self {
+            Self::OneElement(item) => (item.clone(), item),
+            Self::MinMax(min, max) => (min, max),
+        }
+    }
+}
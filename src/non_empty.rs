@@ -1,16 +1,89 @@
 //! The non-empty iterator core traits.
 
 use core::{
+    array,
     cmp::Ordering,
     iter::{self, Product, Sum},
+    ops::ControlFlow,
 };
 
+#[cfg(feature = "allocator_api")]
+use core::alloc::Allocator;
+
+#[cfg(feature = "alloc")]
+use core::fmt::{Display, Write};
+
+#[cfg(feature = "std")]
+use core::{fmt::Debug, hash::Hash};
+
+#[cfg(feature = "std")]
+use std::collections::{HashMap, hash_map::Entry};
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    rc::Rc,
+    string::String,
+    vec::{self, Vec},
+};
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
 use non_zero_size::Size;
 
+#[cfg(feature = "nonempty")]
+use nonempty::NonEmpty;
+
+#[cfg(feature = "nonempty")]
+use crate::chunks_non_empty::ChunksNonEmpty;
+
+#[cfg(feature = "alloc")]
+use crate::{
+    chunks_by_total::ChunksByTotal, put_back::PutBack, rotate_left::RotateLeft,
+    skip_last::SkipLast, sorted_stream::SortedStream, take_last::TakeLast,
+    windows_exact::WindowsExact,
+};
+
 use crate::{
-    adapter::NonEmptyAdapter, chain::Chain, cloned::Cloned, copied::Copied, cycle::Cycle,
-    enumerate::Enumerate, flat_map::FlatMap, flatten::Flatten, fuse::Fuse, inspect::Inspect,
-    map::Map, peeked::Peeked, rev::Rev, step_by::StepBy, take::Take, zip::Zip,
+    adapter::NonEmptyAdapter,
+    chain::{Chain, ChainNonEmpty},
+    cloned::Cloned,
+    copied::Copied,
+    count_one::CountOne,
+    cycle::{Cycle, CycleN},
+    dedup::Dedup,
+    enumerate::Enumerate,
+    enumerate_by::EnumerateBy,
+    enumerate_remaining::EnumerateRemaining,
+    enumerate_size::EnumerateSize,
+    flat_map::{FlatMap, FlatMapNonEmpty},
+    flatten::Flatten,
+    fuse::Fuse,
+    indices::{ZipIndices, indices},
+    inspect::Inspect,
+    inspect_count::InspectCount,
+    inspect_err::InspectErr,
+    interleave_shortest::InterleaveShortest,
+    intersperse::{Intersperse, IntersperseWith},
+    map::Map,
+    map_first::MapFirst,
+    map_last::MapLast,
+    map_result::{MapErr, MapOk},
+    map_while_inclusive::MapWhileInclusive,
+    min_max::MinMaxResult,
+    pad_to::PadTo,
+    peeked::Peeked,
+    reduce_segments::ReduceSegments,
+    rev::Rev,
+    running::{RunningMax, RunningMin},
+    saturating::SaturatingAdd,
+    step_by::StepBy,
+    take::Take,
+    windowed_fold::WindowedFold,
+    zip::Zip,
+    zip_with_next::ZipWithNext,
 };
 
 /// Represents [`Iterator`] that is guaranteed to be non-empty
@@ -45,6 +118,38 @@ pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
         (item, iterator)
     }
 
+    /// Consumes the non-empty iterator, returning the first item along with
+    /// the possibly empty rest of the items.
+    ///
+    /// This is equivalent to calling [`consume`], clarifying the intent.
+    ///
+    /// [`consume`]: NonEmptyIterator::consume
+    #[must_use]
+    fn into_first_rest(self) -> (Self::Item, Self::IntoIter) {
+        self.consume()
+    }
+
+    /// Consumes the non-empty iterator, returning the first item along with
+    /// the rest of the items, provided they are non-empty.
+    ///
+    /// This is equivalent to calling [`consume`] followed by [`try_into_non_empty_iter`]
+    /// on the rest, and allows to check whether there is more than one item.
+    ///
+    /// [`consume`]: NonEmptyIterator::consume
+    /// [`try_into_non_empty_iter`]: TryIntoNonEmptyIterator::try_into_non_empty_iter
+    #[must_use]
+    #[allow(clippy::type_complexity)]
+    fn into_non_empty_parts(
+        self,
+    ) -> (
+        Self::Item,
+        Option<NonEmptyAdapter<iter::Peekable<Self::IntoIter>>>,
+    ) {
+        let (item, rest) = self.consume();
+
+        (item, rest.try_into_non_empty_iter())
+    }
+
     /// Consumes the non-empty iterator, returning the item count.
     ///
     /// See also [`count`] on [`Iterator`].
@@ -63,10 +168,72 @@ pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
         unsafe { Size::new_unchecked(count) }
     }
 
+    /// Consumes at most `max + 1` items of the non-empty iterator,
+    /// returning the item count if it does not exceed `max`.
+    ///
+    /// See also [`count`].
+    ///
+    /// # Difference from [`count`]
+    ///
+    /// Note that this function stops after going past `max`,
+    /// so it returns even for infinite iterators. If the non-empty iterator
+    /// has more than `max` items, [`None`] is returned.
+    ///
+    /// # Non-zero
+    ///
+    /// The returned count, if any, is guaranteed to be non-zero.
+    ///
+    /// [`count`]: NonEmptyIterator::count
+    #[must_use]
+    fn count_up_to(self, max: Size) -> Option<Size> {
+        let max = max.get();
+
+        let count = self.into_iter().take(max.saturating_add(1)).count();
+
+        if count > max {
+            return None;
+        }
+
+        // SAFETY: the implementor guarantees the iterator is non-empty
+        // therefore, `count` is non-zero
+        Some(unsafe { Size::new_unchecked(count) })
+    }
+
+    /// Consumes the non-empty iterator, returning the number of items matching the predicate.
+    ///
+    /// Note that the returned count can be zero, as none of the items may match.
+    ///
+    /// See also [`count_where_at_least_one`].
+    ///
+    /// [`count_where_at_least_one`]: NonEmptyIterator::count_where_at_least_one
+    #[must_use]
+    fn count_where<P: FnMut(&Self::Item) -> bool>(self, predicate: P) -> usize {
+        self.into_iter().filter(predicate).count()
+    }
+
+    /// Similar to [`count_where`], but returns [`None`] if none of the items match.
+    ///
+    /// # Non-zero
+    ///
+    /// The returned count, if any, is guaranteed to be non-zero.
+    ///
+    /// [`count_where`]: NonEmptyIterator::count_where
+    #[must_use]
+    fn count_where_at_least_one<P: FnMut(&Self::Item) -> bool>(self, predicate: P) -> Option<Size> {
+        let count = self.count_where(predicate);
+
+        // SAFETY: `count` is checked to be non-zero
+        (count > 0).then(|| unsafe { Size::new_unchecked(count) })
+    }
+
     /// Creates non-empty iterators that yield the current count and the item during iteration.
     ///
     /// See also [`enumerate`] on [`Iterator`].
     ///
+    /// If the non-empty iterator is both [`ExactSizeNonEmptyIterator`] and
+    /// [`DoubleEndedNonEmptyIterator`], the returned one can be consumed from the back
+    /// via [`Enumerate::consume_back`], with the last index computed from the length.
+    ///
     /// # Non-empty
     ///
     /// The returned iterator is guaranteed to be non-empty.
@@ -76,6 +243,66 @@ pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
         Enumerate::new(self)
     }
 
+    /// Creates non-empty iterators that yield the current index, starting from one,
+    /// and the item during iteration.
+    ///
+    /// See also [`enumerate`].
+    ///
+    /// # Difference from [`enumerate`]
+    ///
+    /// Note that the indices start from one, and are therefore non-zero.
+    ///
+    /// # Panics
+    ///
+    /// Iterating over more than [`usize::MAX`] items panics, as the index would overflow.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`enumerate`]: NonEmptyIterator::enumerate
+    fn enumerate_size(self) -> EnumerateSize<Self> {
+        EnumerateSize::new(self)
+    }
+
+    /// Creates non-empty iterators that yield the current index, starting from the given one
+    /// and advancing by the given step, and the item during iteration.
+    ///
+    /// See also [`enumerate_size`].
+    ///
+    /// # Panics
+    ///
+    /// Yielding the item with the index exceeding [`usize::MAX`] panics.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`enumerate_size`]: NonEmptyIterator::enumerate_size
+    fn enumerate_by(self, start: Size, step: Size) -> EnumerateBy<Self> {
+        EnumerateBy::new(self, start, step)
+    }
+
+    /// Creates non-empty iterators that yield the number of remaining items,
+    /// including the current one, and the item during iteration.
+    ///
+    /// The first count yielded is the length of the non-empty iterator,
+    /// and the last one is always one, which is handy for progress reporting.
+    ///
+    /// See also [`enumerate_size`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`enumerate_size`]: NonEmptyIterator::enumerate_size
+    fn enumerate_remaining(self) -> EnumerateRemaining<Self>
+    where
+        Self: ExactSizeNonEmptyIterator,
+    {
+        EnumerateRemaining::new(self)
+    }
+
     /// Peeks at the next item of the non-empty iterator, returning it along
     /// with the possibly empty iterator.
     ///
@@ -95,6 +322,19 @@ pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
         Peeked::new(item, rest)
     }
 
+    /// Creates non-empty iterators that allow putting items back,
+    /// so that they are yielded before the rest of the items.
+    ///
+    /// The items can be put back both before and during the iteration.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    #[cfg(feature = "alloc")]
+    fn into_put_back(self) -> PutBack<Self> {
+        PutBack::new(self)
+    }
+
     /// Links the non-empty iterator with the provided possibly empty iterator.
     ///
     /// See also [`chain`] on [`Iterator`].
@@ -108,6 +348,67 @@ pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
         Chain::new(self, other.into_iter())
     }
 
+    /// Links the non-empty iterator with the provided non-empty iterator.
+    ///
+    /// See also [`chain`].
+    ///
+    /// # Difference from [`chain`]
+    ///
+    /// Note that the argument is required to be [`IntoNonEmptyIterator`], so that the returned
+    /// iterator is non-empty from either end, and its length is known if both lengths are.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`chain`]: NonEmptyIterator::chain
+    fn chain_non_empty<I: IntoNonEmptyIterator<Item = Self::Item>>(
+        self,
+        other: I,
+    ) -> ChainNonEmpty<Self, I::IntoNonEmptyIter> {
+        ChainNonEmpty::new(self, other.into_non_empty_iter())
+    }
+
+    /// Alternates between the items of the non-empty iterator and the provided one,
+    /// starting with this one.
+    ///
+    /// The items are yielded in pairs, and the iteration stops once either of the iterators
+    /// is exhausted, so the trailing item without the pair is not yielded.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    fn interleave_shortest<I: IntoNonEmptyIterator<Item = Self::Item>>(
+        self,
+        other: I,
+    ) -> InterleaveShortest<Self, I::IntoNonEmptyIter> {
+        InterleaveShortest::new(self, other.into_non_empty_iter())
+    }
+
+    /// Appends the given item to the end of the non-empty iterator.
+    ///
+    /// This is the same as [`chain`] with [`iter::once`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`chain`]: NonEmptyIterator::chain
+    fn push_back(self, item: Self::Item) -> Chain<Self, iter::Once<Self::Item>> {
+        self.chain(iter::once(item))
+    }
+
+    /// Prepends the given item to the start of the non-empty iterator.
+    ///
+    /// The given item becomes the peeked one, while the non-empty iterator becomes the rest.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    fn push_front(self, item: Self::Item) -> Peeked<Self::IntoIter> {
+        Peeked::new(item, self.into_iter())
+    }
+
     /// Creates non-empty iterators that clone the items of the underlying non-empty iterator.
     ///
     /// See also [`cloned`] on [`Iterator`].
@@ -161,6 +462,39 @@ pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
         Zip::new(self, other.into_non_empty_iter())
     }
 
+    /// Creates iterators that yield each item along with the following one.
+    ///
+    /// The last item is only yielded as the successor, since it has no successor itself.
+    /// Each item except for the first one is cloned once.
+    ///
+    /// Note that the returned iterator is empty if there is only one item.
+    fn zip_with_next(self) -> ZipWithNext<Self::IntoIter>
+    where
+        Self::Item: Clone,
+    {
+        let (item, rest) = self.consume();
+
+        ZipWithNext::new(item, rest)
+    }
+
+    /// Zips the non-empty iterator with indices starting from one.
+    ///
+    /// This is equivalent to calling [`zip`] on [`indices`] with the non-empty iterator,
+    /// which allows to compose the result with other zips.
+    ///
+    /// See also [`enumerate_size`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`zip`]: NonEmptyIterator::zip
+    /// [`indices`]: crate::indices::indices
+    /// [`enumerate_size`]: NonEmptyIterator::enumerate_size
+    fn zip_indices(self) -> ZipIndices<Self> {
+        indices().zip(self)
+    }
+
     /// Sums the items of the non-empty iterator together.
     ///
     /// See also [`sum`] on [`Iterator`].
@@ -171,6 +505,78 @@ pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
         self.into_iter().sum()
     }
 
+    /// Sums the items of the non-empty iterator as [`f64`] values using compensated summation,
+    /// which greatly reduces the accumulated rounding error compared to [`sum`].
+    ///
+    /// This implements the Kahan-Babuska variant, which also compensates
+    /// when the item being added is larger in magnitude than the running sum.
+    ///
+    /// [`sum`]: NonEmptyIterator::sum
+    #[must_use]
+    fn sum_kahan(self) -> f64
+    where
+        Self::Item: Into<f64>,
+    {
+        let (item, rest) = self.consume();
+
+        let mut sum = item.into();
+        let mut compensation = 0.0;
+
+        for item in rest {
+            let value = item.into();
+
+            let total = sum + value;
+
+            // recover the low-order bits lost when adding the smaller value
+            if sum.abs() >= value.abs() {
+                compensation += (sum - total) + value;
+            } else {
+                compensation += (value - total) + sum;
+            }
+
+            sum = total;
+        }
+
+        sum + compensation
+    }
+
+    /// Sums one [`CountOne`] unit per item of the non-empty iterator, returning the count.
+    ///
+    /// This is equivalent to [`count`], except that the count saturates
+    /// at [`usize::MAX`] instead of overflowing.
+    ///
+    /// # Non-zero
+    ///
+    /// The returned count is guaranteed to be non-zero.
+    ///
+    /// [`count`]: NonEmptyIterator::count
+    #[must_use]
+    fn sum_count(self) -> Size {
+        let count: Option<Size> = self.into_iter().map(|_| CountOne).sum();
+
+        // SAFETY: the implementor guarantees the iterator is non-empty
+        // therefore, `count` has to contain some value
+        unsafe { count.unwrap_unchecked() }
+    }
+
+    /// Sums the items of the non-empty iterator together,
+    /// saturating at the numeric bounds instead of overflowing.
+    ///
+    /// See also [`sum`].
+    ///
+    /// # Difference from [`sum`]
+    ///
+    /// Note that the summation starts from the first item, so there is no need for zero.
+    ///
+    /// [`sum`]: NonEmptyIterator::sum
+    #[must_use]
+    fn sum_saturating(self) -> Self::Item
+    where
+        Self::Item: SaturatingAdd,
+    {
+        self.reduce(SaturatingAdd::saturating_add)
+    }
+
     /// Multiplies the items of the non-empty iterator together.
     ///
     /// See also [`product`] on [`Iterator`].
@@ -230,660 +636,3122 @@ pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
         unsafe { output.unwrap_unchecked() }
     }
 
-    /// Converts the non-empty iterator of pairs into the pair of collections.
+    /// Reduces the items of the non-empty iterator in lanes of `N` items,
+    /// which can help the compiler vectorize the reduction.
     ///
-    /// See also [`unzip`] on [`Iterator`].
+    /// Each full lane is combined into the partial result with `combine`, and the partial
+    /// results are then reduced with `function`. The final lane, if not full, is not passed
+    /// to `combine`; instead, its items are reduced with `function` along with the partials.
     ///
-    /// [`unzip`]: Iterator::unzip
-    fn unzip<T, U, C: Default + Extend<T>, D: Default + Extend<U>>(self) -> (C, D)
+    /// Note that `N` must be non-zero, otherwise the code fails to compile.
+    ///
+    /// Note that this function always returns some value, as the iterator is non-empty.
+    ///
+    /// See also [`reduce`].
+    ///
+    /// [`reduce`]: NonEmptyIterator::reduce
+    #[must_use]
+    fn reduce_lanes<const N: usize, C, F>(self, mut combine: C, mut function: F) -> Self::Item
     where
-        Self: NonEmptyIterator<Item = (T, U)>,
+        C: FnMut([Self::Item; N]) -> Self::Item,
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
     {
-        self.into_iter().unzip()
+        const { assert!(N > 0, "lane count must be non-zero") };
+
+        let mut iterator = self.into_iter().fuse();
+
+        let mut output = None;
+
+        let mut merge = |output: Option<Self::Item>, item| match output {
+            Some(output) => function(output, item),
+            None => item,
+        };
+
+        loop {
+            let lane: [Option<Self::Item>; N] = array::from_fn(|_| iterator.next());
+
+            // the iterator is fused, so the lane is full if its last slot is filled
+            if lane[N - 1].is_none() {
+                for item in lane.into_iter().flatten() {
+                    output = Some(merge(output, item));
+                }
+
+                break;
+            }
+
+            // SAFETY: the lane is full, therefore each slot has to contain some value
+            let lane = lane.map(|item| unsafe { item.unwrap_unchecked() });
+
+            output = Some(merge(output, combine(lane)));
+        }
+
+        // SAFETY: the implementor guarantees the iterator is non-empty
+        // therefore, `output` has to contain some value
+        unsafe { output.unwrap_unchecked() }
     }
 
-    /// Equivalent to [`collect`] on [`Iterator`].
+    /// Similar to [`reduce`], but also returns the number of reduced items.
     ///
-    /// See also [`collect_non_empty`].
+    /// This is useful for computing averages with custom combining functions.
     ///
-    /// [`collect`]: Iterator::collect
-    /// [`collect_non_empty`]: NonEmptyIterator::collect_non_empty
-    fn collect<C: FromIterator<Self::Item>>(self) -> C {
-        self.into_iter().collect()
+    /// # Non-zero
+    ///
+    /// The returned count is guaranteed to be non-zero.
+    ///
+    /// [`reduce`]: NonEmptyIterator::reduce
+    #[must_use]
+    fn reduce_counted<F>(self, mut function: F) -> (Self::Item, Size)
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        let (item, rest) = self.consume();
+
+        let (output, count) = rest.fold((item, 1usize), |(output, count), item| {
+            (function(output, item), count.saturating_add(1))
+        });
+
+        // SAFETY: `count` starts from one and saturates, therefore it is non-zero
+        let count = unsafe { Size::new_unchecked(count) };
+
+        (output, count)
     }
 
-    /// Collects the items of the non-empty iterator into the collection.
-    ///
-    /// See also [`collect`].
+    /// Reduces the collections yielded by the non-empty iterator into the first one,
+    /// extending it with the items of the rest of the collections.
     ///
-    /// # Difference from [`Iterator`]
+    /// This reuses the first collection, for instance, its allocation.
     ///
-    /// Note that the collection is required to be [`FromNonEmptyIterator`].
+    /// See also [`reduce`].
     ///
-    /// [`collect`]: NonEmptyIterator::collect
-    fn collect_non_empty<C: FromNonEmptyIterator<Self::Item>>(self) -> C {
-        C::from_non_empty_iter(self)
+    /// [`reduce`]: NonEmptyIterator::reduce
+    #[must_use]
+    fn reduce_extend<T>(self) -> Self::Item
+    where
+        Self::Item: IntoIterator<Item = T> + Extend<T>,
+    {
+        let (mut output, rest) = self.consume();
+
+        for item in rest {
+            output.extend(item);
+        }
+
+        output
     }
 
-    /// Similar to [`map`], but flattens produced non-empty iterators.
-    ///
-    /// See also [`flat_map`] on [`Iterator`].
+    /// Creates non-empty iterators that reduce each segment of items into the single one
+    /// by repeatedly applying the given function.
     ///
-    /// # Difference from [`Iterator`]
+    /// Each item matching the predicate starts the new segment, with the exception
+    /// of the first item, which always starts the first segment. Therefore, the boundary
+    /// items are reduced along with the items following them.
     ///
-    /// Note that the function is required to return [`IntoNonEmptyIterator`].
+    /// See also [`reduce`].
     ///
     /// # Non-empty
     ///
     /// The returned iterator is guaranteed to be non-empty.
     ///
-    /// [`map`]: NonEmptyIterator::map
-    /// [`flat_map`]: Iterator::flat_map
-    fn flat_map<J: IntoNonEmptyIterator, F: FnMut(Self::Item) -> J>(
-        self,
-        function: F,
-    ) -> FlatMap<Self, J, F> {
-        FlatMap::new(self, function)
+    /// [`reduce`]: NonEmptyIterator::reduce
+    fn reduce_segments<F, P>(self, combine: F, is_boundary: P) -> ReduceSegments<Self, F, P>
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        ReduceSegments::new(self, combine, is_boundary)
     }
 
-    /// Flattens one level of nesting in `self` non-empty iterator.
+    /// Similar to [`reduce`], but also returns whether more than one item was reduced.
     ///
-    /// See also [`flatten`] on [`Iterator`].
+    /// This is useful for choosing between singular and plural forms, for instance.
     ///
-    /// # Difference from [`Iterator`]
+    /// [`reduce`]: NonEmptyIterator::reduce
+    #[must_use]
+    fn reduce_plurality<F>(self, mut function: F) -> (Self::Item, bool)
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        let (item, rest) = self.consume();
+
+        rest.fold((item, false), |(output, _), item| {
+            (function(output, item), true)
+        })
+    }
+
+    /// Similar to [`reduce`], but stops as soon as the accumulated item matches the predicate.
     ///
-    /// Note that the items are required to be [`IntoNonEmptyIterator`].
+    /// The predicate is checked before each application of the function, starting with
+    /// the first item, and the remaining items are left unconsumed once it matches.
     ///
-    /// # Non-empty
+    /// This is useful for operations with absorbing elements, for instance, stopping
+    /// the product once it reaches zero.
     ///
-    /// The returned iterator is guaranteed to be non-empty.
+    /// Note that this function always returns some value, as the iterator is non-empty.
     ///
-    /// [`flatten`]: Iterator::flatten
-    fn flatten(self) -> Flatten<Self>
+    /// [`reduce`]: NonEmptyIterator::reduce
+    #[must_use]
+    fn reduce_while<F, P>(self, mut function: F, mut predicate: P) -> Self::Item
     where
-        Self::Item: IntoNonEmptyIterator,
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+        P: FnMut(&Self::Item) -> bool,
     {
-        Flatten::new(self)
-    }
+        let (mut output, mut rest) = self.consume();
 
-    /// Equivalent to [`filter`] on [`Iterator`].
-    ///
-    /// Note that the returned iterator can be empty, depending on the predicate.
-    ///
-    /// [`filter`]: Iterator::filter
-    fn filter<P: FnMut(&Self::Item) -> bool>(
-        self,
-        predicate: P,
-    ) -> iter::Filter<Self::IntoIter, P> {
-        self.into_iter().filter(predicate)
-    }
+        while !predicate(&output) {
+            let Some(item) = rest.next() else {
+                break;
+            };
 
-    /// Equivalent to [`find`] on [`Iterator`].
-    ///
-    /// [`find`]: Iterator::find
-    fn find<P: FnMut(&Self::Item) -> bool>(self, predicate: P) -> Option<Self::Item> {
-        self.into_iter().find(predicate)
+            output = function(output, item);
+        }
+
+        output
     }
 
-    /// Equivalent to [`filter_map`] on [`Iterator`].
+    /// Similar to [`reduce`], but merges the items into the first one in place.
     ///
-    /// Note that the returned iterator can be empty, depending on the function.
+    /// This is useful when items can be merged without being moved, for instance,
+    /// when extending vectors.
     ///
-    /// [`filter_map`]: Iterator::filter_map
-    fn filter_map<T, F: FnMut(Self::Item) -> Option<T>>(
-        self,
-        function: F,
-    ) -> iter::FilterMap<Self::IntoIter, F> {
-        self.into_iter().filter_map(function)
-    }
-
-    /// Equivalent to [`fold`] on [`Iterator`].
+    /// Note that this function always returns some value, as the iterator is non-empty.
     ///
-    /// [`fold`]: Iterator::fold
+    /// [`reduce`]: NonEmptyIterator::reduce
     #[must_use]
-    fn fold<A, F: FnMut(A, Self::Item) -> A>(self, initial: A, function: F) -> A {
-        self.into_iter().fold(initial, function)
+    fn reduce_mut<F: FnMut(&mut Self::Item, Self::Item)>(self, mut function: F) -> Self::Item {
+        let (mut output, rest) = self.consume();
+
+        for item in rest {
+            function(&mut output, item);
+        }
+
+        output
     }
 
-    /// Creates non-empty iterators that map the items of the non-empty iterator with the function.
-    ///
-    /// See also [`map`] on [`Iterator`].
+    /// Groups the values of the non-empty iterator of pairs by their keys,
+    /// reducing the values of each group with the given function.
     ///
     /// # Non-empty
     ///
-    /// The returned iterator is guaranteed to be non-empty.
-    ///
-    /// [`map`]: Iterator::map
-    fn map<U, F: FnMut(Self::Item) -> U>(self, function: F) -> Map<Self, F> {
-        Map::new(self, function)
+    /// The returned map is guaranteed to be non-empty.
+    #[cfg(feature = "std")]
+    #[must_use]
+    fn reduce_by_key<K: Eq + Hash, V, F: FnMut(V, V) -> V>(self, mut function: F) -> HashMap<K, V>
+    where
+        Self: NonEmptyIterator<Item = (K, V)>,
+    {
+        let mut map = HashMap::new();
+
+        for (key, value) in self {
+            match map.entry(key) {
+                Entry::Occupied(entry) => {
+                    // the reduced value has to be moved out in order to be combined
+                    let (key, reduced) = entry.remove_entry();
+
+                    map.insert(key, function(reduced, value));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+            }
+        }
+
+        map
     }
 
-    /// Returns the maximum item of the non-empty iterator.
-    ///
-    /// See also [`max`] on [`Iterator`].
-    ///
-    /// # Difference from [`Iterator`]
+    /// Converts the non-empty iterator of pairs into the pair of collections.
     ///
-    /// Note that this function always returns some value, as the iterator is non-empty.
+    /// See also [`unzip`] on [`Iterator`].
     ///
-    /// [`max`]: Iterator::max
-    #[must_use]
-    fn max(self) -> Self::Item
+    /// [`unzip`]: Iterator::unzip
+    fn unzip<T, U, C: Default + Extend<T>, D: Default + Extend<U>>(self) -> (C, D)
     where
-        Self::Item: Ord,
+        Self: NonEmptyIterator<Item = (T, U)>,
     {
-        let max = self.into_iter().max();
-
-        // SAFETY: the implementor guarantees the iterator is non-empty
-        // therefore, `max` has to contain some value
-        unsafe { max.unwrap_unchecked() }
+        self.into_iter().unzip()
     }
 
-    /// Returns the maximum item of the non-empty iterator with respect to the comparison function.
+    /// Converts the non-empty iterator of pairs into the pair of [`NonEmpty<T>`] collections.
     ///
-    /// See also [`max_by`] on [`Iterator`].
+    /// See also [`unzip`].
     ///
-    /// # Difference from [`Iterator`]
+    /// # Non-empty
     ///
-    /// Note that this function always returns some value, as the iterator is non-empty.
+    /// The returned collections are guaranteed to be non-empty.
     ///
-    /// [`max_by`]: Iterator::max_by
-    #[must_use]
-    fn max_by<F: FnMut(&Self::Item, &Self::Item) -> Ordering>(self, function: F) -> Self::Item {
-        let max = self.into_iter().max_by(function);
+    /// [`unzip`]: NonEmptyIterator::unzip
+    #[cfg(feature = "nonempty")]
+    fn unzip_non_empty<T, U>(self) -> (NonEmpty<T>, NonEmpty<U>)
+    where
+        Self: NonEmptyIterator<Item = (T, U)>,
+    {
+        let ((left, right), rest) = self.consume();
+
+        let (left_tail, right_tail) = rest.unzip();
+
+        (
+            NonEmpty {
+                head: left,
+                tail: left_tail,
+            },
+            NonEmpty {
+                head: right,
+                tail: right_tail,
+            },
+        )
+    }
 
-        // SAFETY: the implementor guarantees the iterator is non-empty
-        // therefore, `max` has to contain some value
-        unsafe { max.unwrap_unchecked() }
+    /// Equivalent to [`collect`] on [`Iterator`].
+    ///
+    /// See also [`collect_non_empty`].
+    ///
+    /// [`collect`]: Iterator::collect
+    /// [`collect_non_empty`]: NonEmptyIterator::collect_non_empty
+    fn collect<C: FromIterator<Self::Item>>(self) -> C {
+        self.into_iter().collect()
     }
 
-    /// Returns the maximum item of the non-empty iterator with respect to the key function.
+    /// Collects the items of the non-empty iterator into the collection.
     ///
-    /// See also [`max_by_key`] on [`Iterator`].
+    /// See also [`collect`].
     ///
     /// # Difference from [`Iterator`]
     ///
-    /// Note that this function always returns some value, as the iterator is non-empty.
+    /// Note that the collection is required to be [`FromNonEmptyIterator`].
     ///
-    /// [`max_by_key`]: Iterator::max_by_key
-    #[must_use]
-    fn max_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(self, function: F) -> Self::Item {
-        let max = self.into_iter().max_by_key(function);
-
-        // SAFETY: the implementor guarantees the iterator is non-empty
-        // therefore, `max` has to contain some value
-        unsafe { max.unwrap_unchecked() }
+    /// [`collect`]: NonEmptyIterator::collect
+    fn collect_non_empty<C: FromNonEmptyIterator<Self::Item>>(self) -> C {
+        C::from_non_empty_iter(self)
     }
 
-    /// Returns the minimum item of the non-empty iterator.
+    /// Maps the items of the non-empty iterator with the function,
+    /// collecting the results into the collection.
     ///
-    /// See also [`min`] on [`Iterator`].
+    /// This is equivalent to calling [`map`] and then [`collect_non_empty`].
     ///
-    /// # Difference from [`Iterator`]
+    /// [`map`]: NonEmptyIterator::map
+    /// [`collect_non_empty`]: NonEmptyIterator::collect_non_empty
+    fn map_collect<U, C: FromNonEmptyIterator<U>, F: FnMut(Self::Item) -> U>(
+        self,
+        function: F,
+    ) -> C {
+        self.map(function).collect_non_empty()
+    }
+
+    /// Zips the non-empty iterator with the other one, combining the pairs of items
+    /// with the function and collecting the results into the collection.
     ///
-    /// Note that this function always returns some value, as the iterator is non-empty.
+    /// This is equivalent to calling [`zip`], [`map`] and then [`collect_non_empty`].
     ///
-    /// [`min`]: Iterator::min
-    #[must_use]
-    fn min(self) -> Self::Item
+    /// [`zip`]: NonEmptyIterator::zip
+    /// [`map`]: NonEmptyIterator::map
+    /// [`collect_non_empty`]: NonEmptyIterator::collect_non_empty
+    fn zip_map_collect<I, U, C, F>(self, other: I, mut function: F) -> C
     where
-        Self::Item: Ord,
+        I: IntoNonEmptyIterator,
+        C: FromNonEmptyIterator<U>,
+        F: FnMut(Self::Item, I::Item) -> U,
     {
-        let min = self.into_iter().min();
-
-        // SAFETY: the implementor guarantees the iterator is non-empty
-        // therefore, `min` has to contain some value
-        unsafe { min.unwrap_unchecked() }
+        self.zip(other)
+            .map(|(item, other)| function(item, other))
+            .collect_non_empty()
     }
 
-    /// Returns the minimum item of the non-empty iterator with respect to the comparison function.
+    /// Collects the items of the non-empty iterator into [`Box<[T]>`](Box).
     ///
-    /// See also [`min_by`] on [`Iterator`].
+    /// This is equivalent to calling [`collect_non_empty`] with the boxed slice.
     ///
-    /// # Difference from [`Iterator`]
+    /// # Non-empty
     ///
-    /// Note that this function always returns some value, as the iterator is non-empty.
+    /// The returned boxed slice is guaranteed to be non-empty.
     ///
-    /// [`min_by`]: Iterator::min_by
-    #[must_use]
-    fn min_by<F: FnMut(&Self::Item, &Self::Item) -> Ordering>(self, function: F) -> Self::Item {
-        let min = self.into_iter().min_by(function);
-
-        // SAFETY: the implementor guarantees the iterator is non-empty
-        // therefore, `min` has to contain some value
-        unsafe { min.unwrap_unchecked() }
+    /// [`collect_non_empty`]: NonEmptyIterator::collect_non_empty
+    #[cfg(feature = "alloc")]
+    fn collect_boxed_slice(self) -> Box<[Self::Item]> {
+        self.collect_non_empty()
     }
 
-    /// Returns the minimum item of the non-empty iterator with respect to the key function.
+    /// Collects the items of the non-empty iterator into [`Rc<[T]>`](Rc).
     ///
-    /// See also [`min_by_key`] on [`Iterator`].
+    /// This is equivalent to calling [`collect_non_empty`] with the reference-counted slice.
     ///
-    /// # Difference from [`Iterator`]
+    /// # Non-empty
     ///
-    /// Note that this function always returns some value, as the iterator is non-empty.
+    /// The returned reference-counted slice is guaranteed to be non-empty.
     ///
-    /// [`min_by_key`]: Iterator::min_by_key
-    #[must_use]
-    fn min_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(self, function: F) -> Self::Item {
-        let min = self.into_iter().min_by_key(function);
-
-        // SAFETY: the implementor guarantees the iterator is non-empty
-        // therefore, `min` has to contain some value
-        unsafe { min.unwrap_unchecked() }
+    /// [`collect_non_empty`]: NonEmptyIterator::collect_non_empty
+    #[cfg(feature = "alloc")]
+    fn collect_rc_slice(self) -> Rc<[Self::Item]> {
+        self.collect_non_empty()
     }
 
-    /// Returns the `n`-th item of the non-empty iterator.
+    /// Collects the items of the non-empty iterator into [`Arc<[T]>`](Arc).
     ///
-    /// See also [`nth`] on [`Iterator`].
+    /// This is equivalent to calling [`collect_non_empty`] with the atomically
+    /// reference-counted slice.
     ///
-    /// # Difference from [`Iterator`]
+    /// # Non-empty
     ///
-    /// Note that this function expects non-zero `n`, as the first item can be obtained
-    /// via [`consume`].
+    /// The returned atomically reference-counted slice is guaranteed to be non-empty.
     ///
-    /// [`nth`]: Iterator::nth
-    /// [`consume`]: NonEmptyIterator::consume
-    fn nth(self, n: Size) -> Option<Self::Item> {
-        self.into_iter().nth(n.get())
+    /// [`collect_non_empty`]: NonEmptyIterator::collect_non_empty
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    fn collect_arc_slice(self) -> Arc<[Self::Item]> {
+        self.collect_non_empty()
     }
 
-    /// Skips the first given number of items in the non-empty iterator.
-    ///
-    /// See also [`skip`] on [`Iterator`].
+    /// Collects the non-empty iterator of pairs into [`BTreeMap<K, V>`].
     ///
-    /// The returned iterator can be empty, depending on the count.
+    /// This is equivalent to calling [`collect`] with the map,
+    /// which keeps the last value for each key.
     ///
-    /// # Difference from [`Iterator`]
+    /// # Non-empty
     ///
-    /// Note that this function expects non-zero count.
+    /// The returned map is guaranteed to be non-empty.
     ///
-    /// [`skip`]: Iterator::skip
-    fn skip(self, count: Size) -> iter::Skip<Self::IntoIter> {
-        self.into_iter().skip(count.get())
+    /// [`collect`]: NonEmptyIterator::collect
+    #[cfg(feature = "alloc")]
+    fn collect_btree_map<K: Ord, V>(self) -> BTreeMap<K, V>
+    where
+        Self: NonEmptyIterator<Item = (K, V)>,
+    {
+        self.collect()
     }
 
-    /// Takes only the first given number of items from the non-empty iterator.
+    /// Collects the non-empty iterator of pairs into [`HashMap<K, V>`],
+    /// keeping the first value for each key.
     ///
-    /// See also [`take`] on [`Iterator`].
+    /// See also [`collect`].
     ///
-    /// # Difference from [`Iterator`]
+    /// # Difference from [`collect`]
     ///
-    /// Note that this function expects non-zero count in order to guarantee non-emptiness.
+    /// Note that collecting via [`collect`] keeps the last value for each key instead.
     ///
     /// # Non-empty
     ///
-    /// The returned iterator is guaranteed to be non-empty.
+    /// The returned map is guaranteed to be non-empty.
     ///
-    /// [`take`]: Iterator::take
-    fn take(self, count: Size) -> Take<Self> {
-        Take::new(self, count)
+    /// [`collect`]: NonEmptyIterator::collect
+    #[cfg(feature = "std")]
+    fn collect_map_first_wins<K: Eq + Hash, V>(self) -> HashMap<K, V>
+    where
+        Self: NonEmptyIterator<Item = (K, V)>,
+    {
+        let mut map = HashMap::new();
+
+        for (key, value) in self {
+            map.entry(key).or_insert(value);
+        }
+
+        map
     }
 
-    /// Returns the last item of the non-empty iterator.
-    ///
-    /// See also [`last`] on [`Iterator`].
+    /// Joins the items of the non-empty iterator into [`String`], placing the separator
+    /// between adjacent items and appending the terminator, if any, once at the end.
     ///
-    /// # Difference from [`Iterator`]
-    ///
-    /// Note that this function always returns some value, as the iterator is non-empty.
+    /// # Non-empty
     ///
-    /// [`last`]: Iterator::last
-    #[must_use]
-    fn last(self) -> Self::Item {
-        let last = self.into_iter().last();
+    /// The returned string always contains the first item before the terminator.
+    #[cfg(feature = "alloc")]
+    fn collect_separated(self, separator: &str, terminator: Option<&str>) -> String
+    where
+        Self::Item: Display,
+    {
+        let (item, rest) = self.consume();
 
-        // SAFETY: the implementor guarantees the iterator is non-empty
-        // therefore, `last` has to contain some value
-        unsafe { last.unwrap_unchecked() }
+        let mut string = String::new();
+
+        // writing into strings never fails
+        let _ = write!(string, "{item}");
+
+        for item in rest {
+            string.push_str(separator);
+
+            let _ = write!(string, "{item}");
+        }
+
+        if let Some(terminator) = terminator {
+            string.push_str(terminator);
+        }
+
+        string
     }
 
-    /// Steps the non-empty iterator by the given custom amount.
+    /// Concatenates the items of the non-empty iterator into [`String`],
+    /// reserving the given capacity up front.
     ///
-    /// See also [`step_by`] on [`Iterator`].
-    ///
-    /// # Difference from [`Iterator`]
+    /// Reserving enough capacity avoids reallocations while appending the items.
+    #[cfg(feature = "alloc")]
+    fn collect_string_with_capacity(self, capacity: usize) -> String
+    where
+        Self::Item: AsRef<str>,
+    {
+        let mut string = String::with_capacity(capacity);
+
+        for item in self {
+            string.push_str(item.as_ref());
+        }
+
+        string
+    }
+
+    /// Collects at most `N` items of the non-empty iterator into the array,
+    /// returning it along with the number of filled slots.
     ///
-    /// Note that this function expects non-zero step as the [`step_by`] on [`Iterator`]
-    /// panics on zero.
+    /// The items are placed into the leading slots, and the rest of the slots are [`None`].
+    /// This function does not allocate, which makes it suitable for `no_std` environments.
     ///
-    /// # Non-empty
+    /// Note that `N` must be non-zero, otherwise the code fails to compile.
     ///
-    /// The returned iterator is guaranteed to be non-empty.
+    /// # Non-zero
     ///
-    /// [`step_by`]: Iterator::step_by
-    fn step_by(self, step: Size) -> StepBy<Self> {
-        StepBy::new(self, step)
-    }
+    /// The returned count is guaranteed to be non-zero.
+    fn collect_into_array<const N: usize>(self) -> ([Option<Self::Item>; N], Size) {
+        const { assert!(N > 0, "array length must be non-zero") };
 
-    /// Equivalent to [`for_each`] on [`Iterator`].
-    ///
-    /// [`for_each`]: Iterator::for_each
-    fn for_each<F: FnMut(Self::Item)>(self, function: F) {
-        self.into_iter().for_each(function);
+        let (item, rest) = self.consume();
+
+        let mut array = array::from_fn(|_| None);
+
+        array[0] = Some(item);
+
+        let mut filled = 1;
+
+        for (slot, item) in array[1..].iter_mut().zip(rest) {
+            *slot = Some(item);
+
+            filled += 1;
+        }
+
+        // SAFETY: the first slot is always filled, therefore `filled` is non-zero
+        let filled = unsafe { Size::new_unchecked(filled) };
+
+        (array, filled)
     }
 
-    /// Consumes the iterator, exhausting it by dropping all of its items.
+    /// Collects the items of the non-empty iterator into [`Vec<T, A>`]
+    /// using the provided allocator.
     ///
-    /// This is equivalent to calling [`for_each`] with [`drop`].
+    /// See also [`collect`].
     ///
-    /// [`for_each`]: NonEmptyIterator::for_each
-    fn exhaust(self) {
-        self.for_each(drop);
-    }
-
-    /// Equivalent to [`skip_while`] on [`Iterator`].
+    /// # Non-empty
     ///
-    /// Note that the returned iterator can be empty, depending on the predicate.
+    /// The returned vector is guaranteed to be non-empty.
     ///
-    /// [`skip_while`]: Iterator::skip_while
-    fn skip_while<P: FnMut(&Self::Item) -> bool>(
-        self,
-        predicate: P,
-    ) -> iter::SkipWhile<Self::IntoIter, P> {
-        self.into_iter().skip_while(predicate)
+    /// [`collect`]: NonEmptyIterator::collect
+    #[cfg(feature = "allocator_api")]
+    fn collect_vec_in<A: Allocator>(self, allocator: A) -> Vec<Self::Item, A> {
+        let mut vec = Vec::new_in(allocator);
+
+        vec.extend(self);
+
+        vec
     }
 
-    /// Equivalent to [`take_while`] on [`Iterator`].
+    /// Collects the items of the non-empty iterator of results into [`NonEmpty<T>`],
+    /// returning the first encountered error, if any.
     ///
-    /// Note that the returned iterator can be empty, depending on the predicate.
+    /// See also [`collect`].
     ///
-    /// [`take_while`]: Iterator::take_while
-    fn take_while<P: FnMut(&Self::Item) -> bool>(
-        self,
-        predicate: P,
-    ) -> iter::TakeWhile<Self::IntoIter, P> {
-        self.into_iter().take_while(predicate)
+    /// # Non-empty
+    ///
+    /// The returned collection is guaranteed to be non-empty.
+    ///
+    /// [`collect`]: NonEmptyIterator::collect
+    #[cfg(feature = "nonempty")]
+    fn try_collect_non_empty<T, E>(self) -> Result<NonEmpty<T>, E>
+    where
+        Self: NonEmptyIterator<Item = Result<T, E>>,
+    {
+        let (item, rest) = self.consume();
+
+        let head = item?;
+        let tail = rest.collect::<Result<Vec<T>, E>>()?;
+
+        Ok(NonEmpty { head, tail })
     }
 
-    /// Equivalent to [`map_while`] on [`Iterator`].
+    /// Creates non-empty iterators that yield chunks of the given size
+    /// as [`NonEmpty<T>`] collections.
     ///
-    /// Note that the returned iterator can be empty, depending on the predicate.
+    /// Each chunk contains exactly `size` items, except for the last one,
+    /// which contains the remaining items.
     ///
-    /// [`map_while`]: Iterator::map_while
-    fn map_while<T, P: FnMut(Self::Item) -> Option<T>>(
-        self,
-        predicate: P,
-    ) -> iter::MapWhile<Self::IntoIter, P> {
-        self.into_iter().map_while(predicate)
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty, and so is each chunk.
+    #[cfg(feature = "nonempty")]
+    fn chunks_non_empty(self, size: Size) -> ChunksNonEmpty<Self> {
+        ChunksNonEmpty::new(self, size)
     }
 
-    /// Equivalent to [`scan`] on [`Iterator`].
+    /// Similar to [`map`], but flattens produced non-empty iterators.
     ///
-    /// Note that the returned iterator can be empty, depending on the function.
+    /// See also [`flat_map`] on [`Iterator`].
     ///
-    /// [`scan`]: Iterator::scan
-    fn scan<S, T, F: FnMut(&mut S, Self::Item) -> Option<T>>(
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that the function is required to return [`IntoNonEmptyIterator`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`map`]: NonEmptyIterator::map
+    /// [`flat_map`]: Iterator::flat_map
+    fn flat_map<J: IntoNonEmptyIterator, F: FnMut(Self::Item) -> J>(
         self,
-        initial: S,
         function: F,
-    ) -> iter::Scan<Self::IntoIter, S, F> {
-        self.into_iter().scan(initial, function)
+    ) -> FlatMap<Self, J, F> {
+        FlatMap::new(self, function)
     }
 
-    /// Creates non-empty iterators that call the provided function with references to each item.
+    /// Similar to [`flat_map`], but allows observing the boundaries
+    /// between the produced non-empty iterators, called groups.
     ///
-    /// See also [`inspect`] on [`Iterator`].
+    /// The iterator returned from [`into_iter`] tells whether the last yielded item
+    /// started the new group, and exposes the rest of the current group.
     ///
     /// # Non-empty
     ///
     /// The returned iterator is guaranteed to be non-empty.
     ///
-    /// [`inspect`]: Iterator::inspect
-    fn inspect<F: FnMut(&Self::Item)>(self, function: F) -> Inspect<Self, F> {
-        Inspect::new(self, function)
-    }
-
-    /// Equivalent to [`partition`] on [`Iterator`].
-    ///
-    /// [`partition`]: Iterator::partition
-    fn partition<C: Default + Extend<Self::Item>, F: FnMut(&Self::Item) -> bool>(
+    /// [`flat_map`]: NonEmptyIterator::flat_map
+    /// [`into_iter`]: IntoIterator::into_iter
+    fn flat_map_non_empty<J: IntoNonEmptyIterator, F: FnMut(Self::Item) -> J>(
         self,
         function: F,
-    ) -> (C, C) {
-        self.into_iter().partition(function)
+    ) -> FlatMapNonEmpty<Self, J, F> {
+        FlatMapNonEmpty::new(self, function)
     }
 
-    /// Equivalent to [`position`] on [`Iterator`].
+    /// Flattens one level of nesting in `self` non-empty iterator.
     ///
-    /// [`position`]: Iterator::position
-    fn position<P: FnMut(Self::Item) -> bool>(self, predicate: P) -> Option<usize> {
-        self.into_iter().position(predicate)
-    }
-
-    /// Equivalent to [`cmp`] on [`Iterator`].
+    /// See also [`flatten`] on [`Iterator`].
     ///
-    /// [`cmp`]: Iterator::cmp
-    fn cmp<I: IntoIterator<Item = Self::Item>>(self, other: I) -> Ordering
-    where
-        Self::Item: Ord,
-    {
-        self.into_iter().cmp(other)
-    }
-
-    /// Equivalent to [`partial_cmp`] on [`Iterator`].
+    /// # Difference from [`Iterator`]
     ///
-    /// [`partial_cmp`]: Iterator::partial_cmp
-    fn partial_cmp<I: IntoIterator>(self, other: I) -> Option<Ordering>
-    where
-        Self::Item: PartialOrd<I::Item>,
-    {
-        self.into_iter().partial_cmp(other)
-    }
-
-    /// Equivalent to [`eq`] on [`Iterator`].
+    /// Note that the items are required to be [`IntoNonEmptyIterator`].
     ///
-    /// [`eq`]: Iterator::eq
-    fn eq<I: IntoIterator>(self, other: I) -> bool
-    where
-        Self::Item: PartialEq<I::Item>,
-    {
-        self.into_iter().eq(other)
-    }
-
-    /// Equivalent to [`ne`] on [`Iterator`].
+    /// # Non-empty
     ///
-    /// [`ne`]: Iterator::ne
-    fn ne<I: IntoIterator>(self, other: I) -> bool
-    where
-        Self::Item: PartialEq<I::Item>,
-    {
-        self.into_iter().ne(other)
-    }
-
-    /// Equivalent to [`lt`] on [`Iterator`].
+    /// The returned iterator is guaranteed to be non-empty.
     ///
-    /// [`lt`]: Iterator::lt
-    fn lt<I: IntoIterator>(self, other: I) -> bool
+    /// [`flatten`]: Iterator::flatten
+    fn flatten(self) -> Flatten<Self>
     where
-        Self::Item: PartialOrd<I::Item>,
+        Self::Item: IntoNonEmptyIterator,
     {
-        self.into_iter().lt(other)
+        Flatten::new(self)
     }
 
-    /// Equivalent to [`le`] on [`Iterator`].
+    /// Flattens one level of nesting in `self` non-empty iterator,
+    /// where the items are possibly empty.
     ///
-    /// [`le`]: Iterator::le
-    fn le<I: IntoIterator>(self, other: I) -> bool
-    where
-        Self::Item: PartialOrd<I::Item>,
-    {
-        self.into_iter().le(other)
-    }
-
-    /// Equivalent to [`gt`] on [`Iterator`].
+    /// This is the same as [`Iterator::flatten`], so the returned iterator
+    /// can be empty, as all of the items can be empty.
     ///
-    /// [`gt`]: Iterator::gt
-    fn gt<I: IntoIterator>(self, other: I) -> bool
+    /// See also [`flatten`].
+    ///
+    /// [`flatten`]: NonEmptyIterator::flatten
+    fn flatten_iter(self) -> iter::Flatten<Self::IntoIter>
     where
-        Self::Item: PartialOrd<I::Item>,
+        Self::Item: IntoIterator,
     {
-        self.into_iter().gt(other)
+        self.into_iter().flatten()
     }
 
-    /// Equivalent to [`ge`] on [`Iterator`].
+    /// Equivalent to [`filter`] on [`Iterator`].
     ///
-    /// [`ge`]: Iterator::ge
-    fn ge<I: IntoIterator>(self, other: I) -> bool
-    where
-        Self::Item: PartialOrd<I::Item>,
-    {
-        self.into_iter().ge(other)
+    /// Note that the returned iterator can be empty, depending on the predicate.
+    ///
+    /// [`filter`]: Iterator::filter
+    fn filter<P: FnMut(&Self::Item) -> bool>(
+        self,
+        predicate: P,
+    ) -> iter::Filter<Self::IntoIter, P> {
+        self.into_iter().filter(predicate)
     }
 
-    /// Equivalent to [`is_sorted`] on [`Iterator`].
+    /// Equivalent to [`find`] on [`Iterator`].
     ///
-    /// [`is_sorted`]: Iterator::is_sorted
-    #[allow(clippy::wrong_self_convention)]
-    fn is_sorted(self) -> bool
-    where
-        Self::Item: PartialOrd,
-    {
-        self.into_iter().is_sorted()
+    /// [`find`]: Iterator::find
+    fn find<P: FnMut(&Self::Item) -> bool>(self, predicate: P) -> Option<Self::Item> {
+        self.into_iter().find(predicate)
     }
 
-    /// Equivalent to [`is_sorted_by`] on [`Iterator`].
+    /// Equivalent to [`filter_map`] on [`Iterator`].
     ///
-    /// [`is_sorted_by`]: Iterator::is_sorted_by
-    #[allow(clippy::wrong_self_convention)]
-    fn is_sorted_by<F: FnMut(&Self::Item, &Self::Item) -> bool>(self, function: F) -> bool {
-        self.into_iter().is_sorted_by(function)
+    /// Note that the returned iterator can be empty, depending on the function.
+    ///
+    /// [`filter_map`]: Iterator::filter_map
+    fn filter_map<T, F: FnMut(Self::Item) -> Option<T>>(
+        self,
+        function: F,
+    ) -> iter::FilterMap<Self::IntoIter, F> {
+        self.into_iter().filter_map(function)
     }
 
-    /// Equivalent to [`is_sorted_by_key`] on [`Iterator`].
+    /// Equivalent to [`fold`] on [`Iterator`].
     ///
-    /// [`is_sorted_by_key`]: Iterator::is_sorted_by_key
-    #[allow(clippy::wrong_self_convention)]
-    fn is_sorted_by_key<K: PartialOrd, F: FnMut(Self::Item) -> K>(self, function: F) -> bool {
-        self.into_iter().is_sorted_by_key(function)
+    /// [`fold`]: Iterator::fold
+    #[must_use]
+    fn fold<A, F: FnMut(A, Self::Item) -> A>(self, initial: A, function: F) -> A {
+        self.into_iter().fold(initial, function)
     }
 
-    /// Similar to [`collect`], but extends the provided collection instead of creating new ones.
+    /// Folds the items of the non-empty iterator, allowing the function
+    /// to either continue with the new accumulator or break with the value.
     ///
-    /// Returns the provided collection after extending.
+    /// Returns [`Break`] with the value the function broke with, if any,
+    /// or [`Continue`] with the final accumulator otherwise.
     ///
-    /// [`collect`]: NonEmptyIterator::collect
-    fn collect_into<C: Extend<Self::Item>>(self, collection: &mut C) -> &mut C {
-        collection.extend(self);
-
-        collection
-    }
-
-    /// Equivalent to [`find_map`] on [`Iterator`].
+    /// This is equivalent to calling [`try_fold`] on [`Iterator`] with [`ControlFlow`].
     ///
-    /// [`find_map`]: Iterator::find_map
-    fn find_map<T, F: FnMut(Self::Item) -> Option<T>>(self, function: F) -> Option<T> {
-        self.into_iter().find_map(function)
+    /// [`Break`]: ControlFlow::Break
+    /// [`Continue`]: ControlFlow::Continue
+    /// [`try_fold`]: Iterator::try_fold
+    fn fold_control<A, B, F: FnMut(A, Self::Item) -> ControlFlow<B, A>>(
+        self,
+        initial: A,
+        function: F,
+    ) -> ControlFlow<B, A> {
+        self.into_iter().try_fold(initial, function)
     }
 
-    /// Fuses the non-empty iterator, ensuring that once it returns [`None`],
-    /// it will return [`None`] forever afterwards.
+    /// Creates non-empty iterators that map the items of the non-empty iterator with the function.
     ///
-    /// See also [`fuse`] on [`Iterator`].
+    /// See also [`map`] on [`Iterator`].
     ///
     /// # Non-empty
     ///
     /// The returned iterator is guaranteed to be non-empty.
     ///
-    /// [`fuse`]: Iterator::fuse
-    fn fuse(self) -> Fuse<Self> {
-        Fuse::new(self)
+    /// [`map`]: Iterator::map
+    fn map<U, F: FnMut(Self::Item) -> U>(self, function: F) -> Map<Self, F> {
+        Map::new(self, function)
     }
 
-    /// Reverses the iteration in non-empty iterators.
+    /// Creates non-empty iterators over results that map each success value
+    /// with the function, leaving errors unchanged.
     ///
-    /// See also [`rev`] on [`Iterator`].
+    /// See also [`map`] on [`Result`].
     ///
     /// # Non-empty
     ///
     /// The returned iterator is guaranteed to be non-empty.
     ///
-    /// [`rev`]: Iterator::rev
-    fn rev(self) -> Rev<Self>
+    /// [`map`]: Result::map
+    fn map_ok<T, U, E, F: FnMut(T) -> U>(self, function: F) -> MapOk<Self, F>
     where
-        Self::IntoIter: DoubleEndedIterator,
+        Self: NonEmptyIterator<Item = Result<T, E>>,
     {
-        Rev::new(self)
+        MapOk::new(self, function)
     }
 
-    /// Repeats the non-empty iterator endlessly.
+    /// Creates non-empty iterators over results that map each error
+    /// with the function, leaving success values unchanged.
     ///
-    /// See also [`cycle`] on [`Iterator`].
+    /// See also [`map_err`] on [`Result`].
     ///
     /// # Non-empty
     ///
     /// The returned iterator is guaranteed to be non-empty.
     ///
-    /// [`cycle`]: Iterator::cycle
-    fn cycle(self) -> Cycle<Self>
+    /// [`map_err`]: Result::map_err
+    fn map_err<T, E, R, F: FnMut(E) -> R>(self, function: F) -> MapErr<Self, F>
     where
-        Self::IntoIter: Clone,
+        Self: NonEmptyIterator<Item = Result<T, E>>,
     {
-        Cycle::new(self)
+        MapErr::new(self, function)
     }
-}
 
-/// Represents types that can be created from non-empty iterators.
-///
-/// This is similar to [`FromIterator`], but specifically for non-empty iterators.
-pub trait FromNonEmptyIterator<T>: Sized {
-    /// Creates [`Self`] from the provided non-empty iterator.
-    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = T>>(iterable: I) -> Self;
-}
+    /// Creates iterators over results that yield only the success values.
+    ///
+    /// Note that the returned iterator can be empty, as all of the items can be errors.
+    ///
+    /// See also [`errs`].
+    ///
+    /// [`errs`]: NonEmptyIterator::errs
+    #[allow(clippy::type_complexity)]
+    fn oks<T, E>(self) -> iter::FilterMap<Self::IntoIter, fn(Result<T, E>) -> Option<T>>
+    where
+        Self: NonEmptyIterator<Item = Result<T, E>>,
+    {
+        self.into_iter().filter_map(Result::ok)
+    }
 
-/// Represents types that can be converted into non-empty iterators.
-///
-/// This is similar to [`IntoIterator`], but specifically for non-empty iterators.
-///
-/// Any [`NonEmptyIterator`] is also [`IntoNonEmptyIterator`], akin to how any [`Iterator`]
-/// is also [`IntoIterator`].
-pub trait IntoNonEmptyIterator: IntoIterator {
-    /// What kind of [`NonEmptyIterator`] are we turning this into?
-    type IntoNonEmptyIter: NonEmptyIterator<Item = Self::Item>;
+    /// Creates iterators over results that yield only the errors.
+    ///
+    /// Note that the returned iterator can be empty, as all of the items can be success values.
+    ///
+    /// See also [`oks`].
+    ///
+    /// [`oks`]: NonEmptyIterator::oks
+    #[allow(clippy::type_complexity)]
+    fn errs<T, E>(self) -> iter::FilterMap<Self::IntoIter, fn(Result<T, E>) -> Option<E>>
+    where
+        Self: NonEmptyIterator<Item = Result<T, E>>,
+    {
+        self.into_iter().filter_map(Result::err)
+    }
 
-    /// Converts `self` into [`NonEmptyIterator`].
-    fn into_non_empty_iter(self) -> Self::IntoNonEmptyIter;
-}
+    /// Maps each item with the fallible function, stopping at the first error.
+    ///
+    /// The items are mapped eagerly, as the error has to be known before returning,
+    /// and the mapped values are collected into the vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by the function.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    #[cfg(feature = "alloc")]
+    fn try_map<U, E, F: FnMut(Self::Item) -> Result<U, E>>(
+        self,
+        function: F,
+    ) -> Result<NonEmptyAdapter<vec::IntoIter<U>>, E> {
+        let vec: Vec<U> = self.into_iter().map(function).collect::<Result<_, _>>()?;
 
-impl<I: NonEmptyIterator> IntoNonEmptyIterator for I {
-    type IntoNonEmptyIter = Self;
+        // SAFETY: the non-empty iterator yields at least one item, and every item
+        // was mapped successfully, therefore the vector is non-empty
+        Ok(unsafe { NonEmptyAdapter::new(vec.into_iter()) })
+    }
 
-    fn into_non_empty_iter(self) -> Self::IntoNonEmptyIter {
-        self
+    /// Creates non-empty iterators that map the first item with the function,
+    /// yielding the rest of the items unchanged.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    fn map_first<F: FnOnce(Self::Item) -> Self::Item>(self, function: F) -> MapFirst<Self, F> {
+        MapFirst::new(self, function)
     }
-}
 
-mod sealed {
-    pub trait Sealed {}
-}
+    /// Creates non-empty iterators that map the last item with the function,
+    /// yielding the rest of the items unchanged.
+    ///
+    /// If there is only one item, the function is applied to it.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    fn map_last<F: FnOnce(Self::Item) -> Self::Item>(self, function: F) -> MapLast<Self, F> {
+        MapLast::new(self, function)
+    }
 
-/// Convenience trait implemented for any type that is [`IntoIterator`],
-/// allowing to convert iterables into non-empty ones.
-pub trait TryIntoNonEmptyIterator: sealed::Sealed {
-    /// The type of the items being iterated over.
-    type Item;
+    /// Creates non-empty iterators that place the separator between adjacent items.
+    ///
+    /// This is similar to the unstable `intersperse` method on [`Iterator`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    fn intersperse(self, separator: Self::Item) -> Intersperse<Self>
+    where
+        Self::Item: Clone,
+    {
+        Intersperse::new(self, separator)
+    }
 
-    /// Which kind of [`NonEmptyIterator`] are we turning this into?
-    type IntoNonEmptyIter: NonEmptyIterator<Item = Self::Item>;
+    /// Creates non-empty iterators that place separators computed from the given function
+    /// between adjacent items.
+    ///
+    /// This is similar to the unstable `intersperse_with` method on [`Iterator`].
+    ///
+    /// The function is called exactly once less than the number of items.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    fn intersperse_with<F: FnMut() -> Self::Item>(self, function: F) -> IntersperseWith<Self, F> {
+        IntersperseWith::new(self, function)
+    }
 
-    /// Tries to convert `self` into [`NonEmptyIterator`].
+    /// Returns the maximum item of the non-empty iterator.
     ///
-    /// Returns [`None`] if `self` is empty and therefore can not be converted.
-    fn try_into_non_empty_iter(self) -> Option<Self::IntoNonEmptyIter>;
-}
+    /// See also [`max`] on [`Iterator`].
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that this function always returns some value, as the iterator is non-empty.
+    ///
+    /// [`max`]: Iterator::max
+    #[must_use]
+    fn max(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        let max = self.into_iter().max();
 
-impl<I: IntoIterator> sealed::Sealed for I {}
+        // SAFETY: the implementor guarantees the iterator is non-empty
+        // therefore, `max` has to contain some value
+        unsafe { max.unwrap_unchecked() }
+    }
 
-impl<I: IntoIterator> TryIntoNonEmptyIterator for I {
-    type Item = I::Item;
+    /// Returns the maximum item of the non-empty iterator,
+    /// picking the first one if several items are equally maximum.
+    ///
+    /// See also [`max_last`].
+    ///
+    /// # Difference from [`max`]
+    ///
+    /// Note that [`max`] picks the last one instead.
+    ///
+    /// [`max`]: NonEmptyIterator::max
+    /// [`max_last`]: NonEmptyIterator::max_last
+    #[must_use]
+    fn max_first(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        self.reduce(|max, item| if item > max { item } else { max })
+    }
 
-    type IntoNonEmptyIter = NonEmptyAdapter<iter::Peekable<I::IntoIter>>;
+    /// Returns the maximum item of the non-empty iterator,
+    /// picking the last one if several items are equally maximum.
+    ///
+    /// This is the same as [`max`], but states the tie-breaking explicitly.
+    ///
+    /// See also [`max_first`].
+    ///
+    /// [`max`]: NonEmptyIterator::max
+    /// [`max_first`]: NonEmptyIterator::max_first
+    #[must_use]
+    fn max_last(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        self.max()
+    }
 
-    fn try_into_non_empty_iter(self) -> Option<Self::IntoNonEmptyIter> {
-        let mut peekable = self.into_iter().peekable();
+    /// Returns the maximum item of the non-empty iterator with respect to the comparison function.
+    ///
+    /// See also [`max_by`] on [`Iterator`].
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that this function always returns some value, as the iterator is non-empty.
+    ///
+    /// [`max_by`]: Iterator::max_by
+    #[must_use]
+    fn max_by<F: FnMut(&Self::Item, &Self::Item) -> Ordering>(self, function: F) -> Self::Item {
+        let max = self.into_iter().max_by(function);
 
-        peekable.peek()?;
+        // SAFETY: the implementor guarantees the iterator is non-empty
+        // therefore, `max` has to contain some value
+        unsafe { max.unwrap_unchecked() }
+    }
 
-        // SAFETY: `peekable` is non-empty if we reached here
-        Some(unsafe { NonEmptyAdapter::new(peekable) })
+    /// Returns the maximum item of the non-empty iterator with respect to the key function.
+    ///
+    /// See also [`max_by_key`] on [`Iterator`].
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that this function always returns some value, as the iterator is non-empty.
+    ///
+    /// [`max_by_key`]: Iterator::max_by_key
+    #[must_use]
+    fn max_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(self, function: F) -> Self::Item {
+        let max = self.into_iter().max_by_key(function);
+
+        // SAFETY: the implementor guarantees the iterator is non-empty
+        // therefore, `max` has to contain some value
+        unsafe { max.unwrap_unchecked() }
+    }
+
+    /// Returns all maximum items of the non-empty iterator, in the order they were yielded.
+    ///
+    /// See also [`max`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned vector is guaranteed to be non-empty.
+    ///
+    /// [`max`]: NonEmptyIterator::max
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn max_set(self) -> Vec<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        let (item, rest) = self.consume();
+
+        let mut set = Vec::new();
+
+        set.push(item);
+
+        for item in rest {
+            match item.cmp(&set[0]) {
+                Ordering::Greater => {
+                    set.clear();
+                    set.push(item);
+                }
+                Ordering::Equal => set.push(item),
+                Ordering::Less => {}
+            }
+        }
+
+        set
+    }
+
+    /// Counts the items of the non-empty iterator into the given number of buckets,
+    /// placing each item into the bucket at the index of its value modulo the bucket count.
+    ///
+    /// The returned vector has exactly `buckets` counts, some of which can be zero.
+    ///
+    /// # Non-zero
+    ///
+    /// The sum of the returned counts is guaranteed to be non-zero.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn histogram(self, buckets: Size) -> Vec<usize>
+    where
+        Self::Item: Into<usize>,
+    {
+        let buckets = buckets.get();
+
+        let mut counts: Vec<usize> = iter::repeat_n(0, buckets).collect();
+
+        for item in self {
+            let count = &mut counts[item.into() % buckets];
+
+            *count = count.saturating_add(1);
+        }
+
+        counts
+    }
+
+    /// Returns the positions of all maximum items of the non-empty iterator, in ascending order.
+    ///
+    /// See also [`max_set`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned vector is guaranteed to be non-empty.
+    ///
+    /// [`max_set`]: NonEmptyIterator::max_set
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn positions_max(self) -> Vec<usize>
+    where
+        Self::Item: Ord,
+    {
+        let (mut max, rest) = self.consume();
+
+        let mut positions = Vec::new();
+
+        positions.push(0);
+
+        for (index, item) in rest.enumerate() {
+            // the first item is consumed, so indices are offset by one
+            let position = index + 1;
+
+            match item.cmp(&max) {
+                Ordering::Greater => {
+                    max = item;
+
+                    positions.clear();
+                    positions.push(position);
+                }
+                Ordering::Equal => positions.push(position),
+                Ordering::Less => {}
+            }
+        }
+
+        positions
+    }
+
+    /// Returns the minimum item of the non-empty iterator.
+    ///
+    /// See also [`min`] on [`Iterator`].
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that this function always returns some value, as the iterator is non-empty.
+    ///
+    /// [`min`]: Iterator::min
+    #[must_use]
+    fn min(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        let min = self.into_iter().min();
+
+        // SAFETY: the implementor guarantees the iterator is non-empty
+        // therefore, `min` has to contain some value
+        unsafe { min.unwrap_unchecked() }
+    }
+
+    /// Returns the minimum item of the non-empty iterator,
+    /// picking the first one if several items are equally minimum.
+    ///
+    /// This is the same as [`min`], but states the tie-breaking explicitly.
+    ///
+    /// See also [`min_last`].
+    ///
+    /// [`min`]: NonEmptyIterator::min
+    /// [`min_last`]: NonEmptyIterator::min_last
+    #[must_use]
+    fn min_first(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        self.min()
+    }
+
+    /// Returns the minimum item of the non-empty iterator,
+    /// picking the last one if several items are equally minimum.
+    ///
+    /// See also [`min_first`].
+    ///
+    /// # Difference from [`min`]
+    ///
+    /// Note that [`min`] picks the first one instead.
+    ///
+    /// [`min`]: NonEmptyIterator::min
+    /// [`min_first`]: NonEmptyIterator::min_first
+    #[must_use]
+    fn min_last(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        self.reduce(|min, item| if item <= min { item } else { min })
+    }
+
+    /// Returns the minimum item of the non-empty iterator with respect to the comparison function.
+    ///
+    /// See also [`min_by`] on [`Iterator`].
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that this function always returns some value, as the iterator is non-empty.
+    ///
+    /// [`min_by`]: Iterator::min_by
+    #[must_use]
+    fn min_by<F: FnMut(&Self::Item, &Self::Item) -> Ordering>(self, function: F) -> Self::Item {
+        let min = self.into_iter().min_by(function);
+
+        // SAFETY: the implementor guarantees the iterator is non-empty
+        // therefore, `min` has to contain some value
+        unsafe { min.unwrap_unchecked() }
+    }
+
+    /// Returns the minimum item of the non-empty iterator with respect to the key function.
+    ///
+    /// See also [`min_by_key`] on [`Iterator`].
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that this function always returns some value, as the iterator is non-empty.
+    ///
+    /// [`min_by_key`]: Iterator::min_by_key
+    #[must_use]
+    fn min_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(self, function: F) -> Self::Item {
+        let min = self.into_iter().min_by_key(function);
+
+        // SAFETY: the implementor guarantees the iterator is non-empty
+        // therefore, `min` has to contain some value
+        unsafe { min.unwrap_unchecked() }
+    }
+
+    /// Similar to [`min_by_key`], but guarantees that the key function
+    /// is called exactly once per item.
+    ///
+    /// The key of the current minimum is cached, which is useful for expensive keys.
+    /// If several items are equally minimum, the first one is returned.
+    ///
+    /// [`min_by_key`]: NonEmptyIterator::min_by_key
+    #[must_use]
+    fn min_by_cached_key<K: Ord, F: FnMut(&Self::Item) -> K>(self, mut function: F) -> Self::Item {
+        let (item, rest) = self.consume();
+
+        let key = function(&item);
+
+        let (_, min) = rest.fold((key, item), |(min_key, min), item| {
+            let key = function(&item);
+
+            if key < min_key {
+                (key, item)
+            } else {
+                (min_key, min)
+            }
+        });
+
+        min
+    }
+
+    /// Returns all minimum items of the non-empty iterator, in the order they were yielded.
+    ///
+    /// See also [`min`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned vector is guaranteed to be non-empty.
+    ///
+    /// [`min`]: NonEmptyIterator::min
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn min_set(self) -> Vec<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        let (item, rest) = self.consume();
+
+        let mut set = Vec::new();
+
+        set.push(item);
+
+        for item in rest {
+            match item.cmp(&set[0]) {
+                Ordering::Less => {
+                    set.clear();
+                    set.push(item);
+                }
+                Ordering::Equal => set.push(item),
+                Ordering::Greater => {}
+            }
+        }
+
+        set
+    }
+
+    /// Returns the minimum and maximum items of the non-empty iterator in a single pass,
+    /// distinguishing the case when all items are equal.
+    ///
+    /// If several items are equally minimum, the first one is returned.
+    /// If several items are equally maximum, the last one is returned.
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that there is no empty case, as the iterator is non-empty.
+    #[must_use]
+    fn min_max_eq(self) -> MinMaxResult<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        let (mut min, rest) = self.consume();
+
+        let mut max = None;
+
+        for item in rest {
+            match max {
+                None => {
+                    if item < min {
+                        max = Some(min);
+
+                        min = item;
+                    } else {
+                        max = Some(item);
+                    }
+                }
+                Some(ref mut max) => {
+                    if item < min {
+                        min = item;
+                    } else if item >= *max {
+                        *max = item;
+                    }
+                }
+            }
+        }
+
+        match max {
+            Some(max) if min != max => MinMaxResult::MinMax(min, max),
+            _ => MinMaxResult::OneElement(min),
+        }
+    }
+
+    /// Returns the minimum and the maximum items of the non-empty iterator,
+    /// along with the positions where they were first seen, in one pass.
+    ///
+    /// Only the first item is cloned, as it is both the initial minimum and maximum.
+    ///
+    /// Note that this function always returns some values, as the iterator is non-empty.
+    #[must_use]
+    fn min_max_positions(self) -> ((usize, Self::Item), (usize, Self::Item))
+    where
+        Self::Item: Ord + Clone,
+    {
+        let (item, rest) = self.consume();
+
+        let mut min = (0, item.clone());
+        let mut max = (0, item);
+
+        for (index, item) in (1..).zip(rest) {
+            if item < min.1 {
+                min = (index, item);
+            } else if item > max.1 {
+                max = (index, item);
+            }
+        }
+
+        (min, max)
+    }
+
+    /// Creates non-empty iterators that yield the items in ascending order.
+    ///
+    /// The items are collected into the binary heap when the iteration starts,
+    /// and then popped one by one, so only the requested items are ordered.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    #[cfg(feature = "alloc")]
+    fn sorted_stream(self) -> SortedStream<Self>
+    where
+        Self::Item: Ord,
+    {
+        SortedStream::new(self)
+    }
+
+    /// Creates non-empty iterators that yield the minimum item seen so far at each step.
+    ///
+    /// The first item yielded is always the first item of the non-empty iterator.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    fn running_min(self) -> RunningMin<Self>
+    where
+        Self::Item: Ord + Clone,
+    {
+        RunningMin::new(self)
+    }
+
+    /// Creates non-empty iterators that yield the maximum item seen so far at each step.
+    ///
+    /// The first item yielded is always the first item of the non-empty iterator.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    fn running_max(self) -> RunningMax<Self>
+    where
+        Self::Item: Ord + Clone,
+    {
+        RunningMax::new(self)
+    }
+
+    /// Returns the `n`-th item of the non-empty iterator.
+    ///
+    /// See also [`nth`] on [`Iterator`].
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that this function expects non-zero `n`, as the first item can be obtained
+    /// via [`consume`].
+    ///
+    /// [`nth`]: Iterator::nth
+    /// [`consume`]: NonEmptyIterator::consume
+    fn nth(self, n: Size) -> Option<Self::Item> {
+        self.into_iter().nth(n.get())
+    }
+
+    /// Skips the first given number of items in the non-empty iterator.
+    ///
+    /// See also [`skip`] on [`Iterator`].
+    ///
+    /// The returned iterator can be empty, depending on the count.
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that this function expects non-zero count.
+    ///
+    /// [`skip`]: Iterator::skip
+    fn skip(self, count: Size) -> iter::Skip<Self::IntoIter> {
+        self.into_iter().skip(count.get())
+    }
+
+    /// Skips the last given number of items in the non-empty iterator.
+    ///
+    /// The skipped items are buffered, as it is not known which items are the last ones
+    /// until the underlying iterator is exhausted.
+    ///
+    /// The returned iterator can be empty, depending on the count.
+    #[cfg(feature = "alloc")]
+    fn skip_last(self, count: Size) -> SkipLast<Self::IntoIter> {
+        SkipLast::new(self.into_iter(), count.get())
+    }
+
+    /// Takes only the first given number of items from the non-empty iterator.
+    ///
+    /// See also [`take`] on [`Iterator`].
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that this function expects non-zero count in order to guarantee non-emptiness.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`take`]: Iterator::take
+    fn take(self, count: Size) -> Take<Self> {
+        Take::new(self, count)
+    }
+
+    /// Similar to [`take`], but also returns the actual number of items to be yielded,
+    /// that is, the count clamped to the length of the non-empty iterator.
+    ///
+    /// # Non-zero
+    ///
+    /// The returned count is guaranteed to be non-zero.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`take`]: NonEmptyIterator::take
+    fn take_saturating(self, count: Size) -> (Take<Self>, Size)
+    where
+        Self: ExactSizeNonEmptyIterator,
+    {
+        let actual = self.len().min(count);
+
+        (self.take(count), actual)
+    }
+
+    /// Takes only the last given number of items from the non-empty iterator.
+    ///
+    /// The items are buffered, as it is not known which items are the last ones
+    /// until the underlying iterator is exhausted. If the non-empty iterator has less items
+    /// than the count, all of them are yielded.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    #[cfg(feature = "alloc")]
+    fn take_last(self, count: Size) -> TakeLast<Self> {
+        TakeLast::new(self, count)
+    }
+
+    /// Collects the first given number of items of the non-empty iterator into [`Vec<T>`],
+    /// returning it along with the possibly empty rest of the items.
+    ///
+    /// If the non-empty iterator has less items than the count, all of them are collected.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned vector is guaranteed to be non-empty.
+    #[cfg(feature = "alloc")]
+    fn split_at(self, at: Size) -> (Vec<Self::Item>, Self::IntoIter) {
+        let mut iterator = self.into_iter();
+
+        let prefix = iterator.by_ref().take(at.get()).collect();
+
+        (prefix, iterator)
+    }
+
+    /// Collects the leading items of the non-empty iterator matching the predicate
+    /// into [`Vec<T>`], returning it along with the rest of the items.
+    ///
+    /// Unlike [`take_while`] on [`Iterator`], the first item not matching the predicate
+    /// is not lost, but rather peeked and yielded first from the rest of the items.
+    ///
+    /// Note that both the returned vector and the rest of the items can be empty,
+    /// depending on the predicate.
+    ///
+    /// [`take_while`]: Iterator::take_while
+    #[cfg(feature = "alloc")]
+    fn split_while<P: FnMut(&Self::Item) -> bool>(
+        self,
+        mut predicate: P,
+    ) -> (Vec<Self::Item>, iter::Peekable<Self::IntoIter>) {
+        let mut iterator = self.into_iter().peekable();
+
+        let mut prefix = Vec::new();
+
+        while let Some(item) = iterator.next_if(&mut predicate) {
+            prefix.push(item);
+        }
+
+        (prefix, iterator)
+    }
+
+    /// Pads the non-empty iterator with copies of the fill item until at least
+    /// the given number of items is yielded.
+    ///
+    /// If the non-empty iterator already has enough items, they are yielded unchanged.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    fn pad_to(self, len: Size, fill: Self::Item) -> PadTo<Self>
+    where
+        Self::Item: Clone,
+    {
+        PadTo::new(self, len, fill)
+    }
+
+    /// Returns the last item of the non-empty iterator.
+    ///
+    /// See also [`last`] on [`Iterator`].
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that this function always returns some value, as the iterator is non-empty.
+    ///
+    /// [`last`]: Iterator::last
+    #[must_use]
+    fn last(self) -> Self::Item {
+        let last = self.into_iter().last();
+
+        // SAFETY: the implementor guarantees the iterator is non-empty
+        // therefore, `last` has to contain some value
+        unsafe { last.unwrap_unchecked() }
+    }
+
+    /// Returns the `n`-th item from the end of the non-empty iterator, counting from one.
+    ///
+    /// Returns [`None`] if the non-empty iterator has fewer than `n` items.
+    ///
+    /// See also [`nth_back`] on [`DoubleEndedIterator`].
+    ///
+    /// # Difference from [`DoubleEndedIterator`]
+    ///
+    /// Note that this function expects non-zero `n`, and `nth_from_end(Size::new(1))`
+    /// is equivalent to [`last`], except for being wrapped in [`Some`].
+    ///
+    /// [`nth_back`]: DoubleEndedIterator::nth_back
+    /// [`last`]: NonEmptyIterator::last
+    fn nth_from_end(self, n: Size) -> Option<Self::Item>
+    where
+        Self::IntoIter: DoubleEndedIterator,
+    {
+        self.into_iter().nth_back(n.get() - 1)
+    }
+
+    /// Returns the `n`-th item of the non-empty iterator, counting from one,
+    /// or the last item if the non-empty iterator has fewer than `n` items.
+    ///
+    /// Note that `nth_or_last(Size::new(1))` returns the first item, unlike [`nth`],
+    /// which counts from zero.
+    ///
+    /// Note that this function always returns some value, as the iterator is non-empty.
+    ///
+    /// [`nth`]: NonEmptyIterator::nth
+    #[must_use]
+    fn nth_or_last(self, n: Size) -> Self::Item {
+        let item = self.into_iter().take(n.get()).last();
+
+        // SAFETY: the implementor guarantees the iterator is non-empty, and `n` is non-zero
+        // therefore, `item` has to contain some value
+        unsafe { item.unwrap_unchecked() }
+    }
+
+    /// Rotates the non-empty iterator to the left, yielding the first given number of items
+    /// after the rest of the items.
+    ///
+    /// The first `mid` items are buffered eagerly when the iterator is created.
+    /// If the non-empty iterator has no more than `mid` items, it is yielded unchanged.
+    ///
+    /// See also [`rotate_left`] on slices.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`rotate_left`]: slice::rotate_left
+    #[cfg(feature = "alloc")]
+    fn rotate_left(self, mid: Size) -> RotateLeft<Self> {
+        RotateLeft::new(self, mid)
+    }
+
+    /// Steps the non-empty iterator by the given custom amount.
+    ///
+    /// See also [`step_by`] on [`Iterator`].
+    ///
+    /// # Difference from [`Iterator`]
+    ///
+    /// Note that this function expects non-zero step as the [`step_by`] on [`Iterator`]
+    /// panics on zero.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty, as the first item is always
+    /// yielded, even if the step exceeds the length of the non-empty iterator.
+    ///
+    /// [`step_by`]: Iterator::step_by
+    fn step_by(self, step: Size) -> StepBy<Self> {
+        StepBy::new(self, step)
+    }
+
+    /// Skips the given number of items in the non-empty iterator,
+    /// then steps the rest by the given custom amount.
+    ///
+    /// This is equivalent to calling [`skip`] and then [`step_by`] on the result.
+    ///
+    /// The returned iterator can be empty, depending on the offset.
+    ///
+    /// [`skip`]: NonEmptyIterator::skip
+    /// [`step_by`]: Iterator::step_by
+    fn step_by_offset(self, offset: Size, step: Size) -> iter::StepBy<iter::Skip<Self::IntoIter>> {
+        self.skip(offset).step_by(step.get())
+    }
+
+    /// Creates non-empty iterators that yield non-empty chunks of items, such that the total
+    /// weight of each chunk, computed with the weight function, does not exceed the maximum.
+    ///
+    /// The items are added to the current chunk until adding the next item would exceed
+    /// the maximum, in which case the next item starts the new chunk. Hence any item
+    /// that exceeds the maximum on its own forms its own chunk.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    #[cfg(feature = "alloc")]
+    fn chunks_by_total<F: FnMut(&Self::Item) -> usize>(
+        self,
+        max: usize,
+        weight: F,
+    ) -> ChunksByTotal<Self, F> {
+        ChunksByTotal::new(self, max, weight)
+    }
+
+    /// Creates iterators that fold each overlapping window of `N` consecutive items,
+    /// starting from the initial value every time.
+    ///
+    /// The window is kept in place and shifted as items come, so nothing is allocated
+    /// and the items are not cloned.
+    ///
+    /// Note that the returned iterator can be empty, if there are less than `N` items.
+    ///
+    /// Note that `N` must be non-zero, otherwise the code fails to compile.
+    fn windowed_fold<const N: usize, A: Clone, F: FnMut(A, &[Self::Item; N]) -> A>(
+        self,
+        initial: A,
+        function: F,
+    ) -> WindowedFold<Self::IntoIter, N, A, F> {
+        WindowedFold::new(self.into_iter(), initial, function)
+    }
+
+    /// Creates iterators that yield overlapping windows of `N` consecutive items as arrays.
+    ///
+    /// The windows are yielded as long as there are at least `N` items left; items are cloned
+    /// into each window they belong to.
+    ///
+    /// See also [`windows`] on slices.
+    ///
+    /// Note that the returned iterator can be empty, if there are less than `N` items.
+    ///
+    /// Note that `N` must be non-zero, otherwise the code fails to compile.
+    ///
+    /// [`windows`]: slice::windows
+    #[cfg(feature = "alloc")]
+    fn windows_exact<const N: usize>(self) -> WindowsExact<Self::IntoIter, N>
+    where
+        Self::Item: Clone,
+    {
+        WindowsExact::new(self.into_iter())
+    }
+
+    /// Equivalent to [`for_each`] on [`Iterator`].
+    ///
+    /// [`for_each`]: Iterator::for_each
+    fn for_each<F: FnMut(Self::Item)>(self, function: F) {
+        self.into_iter().for_each(function);
+    }
+
+    /// Consumes the iterator, exhausting it by dropping all of its items.
+    ///
+    /// This is equivalent to calling [`for_each`] with [`drop`].
+    ///
+    /// [`for_each`]: NonEmptyIterator::for_each
+    fn exhaust(self) {
+        self.for_each(drop);
+    }
+
+    /// Equivalent to [`skip_while`] on [`Iterator`].
+    ///
+    /// Note that the returned iterator can be empty, depending on the predicate.
+    ///
+    /// [`skip_while`]: Iterator::skip_while
+    fn skip_while<P: FnMut(&Self::Item) -> bool>(
+        self,
+        predicate: P,
+    ) -> iter::SkipWhile<Self::IntoIter, P> {
+        self.into_iter().skip_while(predicate)
+    }
+
+    /// Equivalent to [`take_while`] on [`Iterator`].
+    ///
+    /// Note that the returned iterator can be empty, depending on the predicate.
+    ///
+    /// [`take_while`]: Iterator::take_while
+    fn take_while<P: FnMut(&Self::Item) -> bool>(
+        self,
+        predicate: P,
+    ) -> iter::TakeWhile<Self::IntoIter, P> {
+        self.into_iter().take_while(predicate)
+    }
+
+    /// Equivalent to [`map_while`] on [`Iterator`].
+    ///
+    /// Note that the returned iterator can be empty, depending on the predicate.
+    ///
+    /// [`map_while`]: Iterator::map_while
+    fn map_while<T, P: FnMut(Self::Item) -> Option<T>>(
+        self,
+        predicate: P,
+    ) -> iter::MapWhile<Self::IntoIter, P> {
+        self.into_iter().map_while(predicate)
+    }
+
+    /// Creates non-empty iterators that map items with the function until it returns `false`
+    /// along with the output, yielding that output as well.
+    ///
+    /// See also [`map_while`].
+    ///
+    /// # Difference from [`map_while`]
+    ///
+    /// Note that the function always produces some output, and the iteration stops
+    /// *after* yielding the first output that is paired with `false`.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`map_while`]: NonEmptyIterator::map_while
+    fn map_while_inclusive<T, F: FnMut(Self::Item) -> (T, bool)>(
+        self,
+        function: F,
+    ) -> MapWhileInclusive<Self, F> {
+        MapWhileInclusive::new(self, function)
+    }
+
+    /// Equivalent to [`scan`] on [`Iterator`].
+    ///
+    /// Note that the returned iterator can be empty, depending on the function.
+    ///
+    /// [`scan`]: Iterator::scan
+    fn scan<S, T, F: FnMut(&mut S, Self::Item) -> Option<T>>(
+        self,
+        initial: S,
+        function: F,
+    ) -> iter::Scan<Self::IntoIter, S, F> {
+        self.into_iter().scan(initial, function)
+    }
+
+    /// Similar to [`scan`], but collects the outputs of the function into the collection,
+    /// returning it along with the final state.
+    ///
+    /// # Difference from [`scan`]
+    ///
+    /// Note that the function always produces some output, so every item is processed.
+    ///
+    /// See also `scan_final`, which is the convenience for collecting into `Vec<T>`.
+    ///
+    /// [`scan`]: NonEmptyIterator::scan
+    fn scan_collect<S, T, C: FromIterator<T>, F: FnMut(&mut S, Self::Item) -> T>(
+        self,
+        initial: S,
+        mut function: F,
+    ) -> (C, S) {
+        let mut state = initial;
+
+        let collection = self
+            .into_iter()
+            .map(|item| function(&mut state, item))
+            .collect();
+
+        (collection, state)
+    }
+
+    /// Similar to [`scan_collect`], but collects the outputs into [`Vec<T>`].
+    ///
+    /// This is the convenience for [`scan_collect`] with the collection fixed to [`Vec<T>`],
+    /// useful for computing running values, such as prefix sums, along with the final state.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned vector is guaranteed to be non-empty.
+    ///
+    /// [`scan_collect`]: NonEmptyIterator::scan_collect
+    #[cfg(feature = "alloc")]
+    fn scan_final<S, T, F: FnMut(&mut S, Self::Item) -> T>(
+        self,
+        initial: S,
+        function: F,
+    ) -> (Vec<T>, S) {
+        self.scan_collect(initial, function)
+    }
+
+    /// Folds the items of the non-empty iterator into the accumulator, collecting
+    /// the outputs of the function into the collection in the same pass.
+    ///
+    /// This is equivalent to calling [`scan_collect`] and swapping the returned values.
+    ///
+    /// [`scan_collect`]: NonEmptyIterator::scan_collect
+    fn fold_map<A, U, C: FromIterator<U>, F: FnMut(&mut A, Self::Item) -> U>(
+        self,
+        initial: A,
+        function: F,
+    ) -> (A, C) {
+        let (collection, accumulator) = self.scan_collect(initial, function);
+
+        (accumulator, collection)
+    }
+
+    /// Creates non-empty iterators that call the provided function with references to each item.
+    ///
+    /// See also [`inspect`] on [`Iterator`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`inspect`]: Iterator::inspect
+    fn inspect<F: FnMut(&Self::Item)>(self, function: F) -> Inspect<Self, F> {
+        Inspect::new(self, function)
+    }
+
+    /// Creates non-empty iterators that print each item to the standard error,
+    /// prefixed by the given label, yielding all items unchanged.
+    ///
+    /// This is the shorthand for [`inspect`] with [`eprintln!`], useful for quick debugging.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`inspect`]: NonEmptyIterator::inspect
+    #[cfg(feature = "std")]
+    fn debug_items(self, label: &'static str) -> Inspect<Self, impl FnMut(&Self::Item)>
+    where
+        Self::Item: Debug,
+    {
+        self.inspect(move |item| eprintln!("{label}: {item:?}"))
+    }
+
+    /// Creates non-empty iterators over results that call the provided function
+    /// with references to each error, yielding all items unchanged.
+    ///
+    /// See also [`inspect_err`] on [`Result`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`inspect_err`]: Result::inspect_err
+    fn inspect_err<T, E, F: FnMut(&E)>(self, function: F) -> InspectErr<Self, F>
+    where
+        Self: NonEmptyIterator<Item = Result<T, E>>,
+    {
+        InspectErr::new(self, function)
+    }
+
+    /// Creates non-empty iterators that count items as they are yielded, calling the provided
+    /// function with the total count once the iteration finishes.
+    ///
+    /// The function is called at most once, when the underlying iterator is exhausted;
+    /// it is not called if the iteration is stopped early.
+    ///
+    /// # Non-zero
+    ///
+    /// The count passed to the function is guaranteed to be non-zero.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    fn inspect_count<F: FnOnce(Size)>(self, function: F) -> InspectCount<Self, F> {
+        InspectCount::new(self, function)
+    }
+
+    /// Creates non-empty iterators that remove consecutive duplicate items.
+    ///
+    /// This is similar to the `dedup` method on vectors, except that it is lazy.
+    ///
+    /// Only the next distinct item is buffered, so this does not require allocation.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    fn dedup(self) -> Dedup<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        Dedup::new(self)
+    }
+
+    /// Equivalent to [`partition`] on [`Iterator`].
+    ///
+    /// [`partition`]: Iterator::partition
+    fn partition<C: Default + Extend<Self::Item>, F: FnMut(&Self::Item) -> bool>(
+        self,
+        function: F,
+    ) -> (C, C) {
+        self.into_iter().partition(function)
+    }
+
+    /// Equivalent to [`position`] on [`Iterator`].
+    ///
+    /// [`position`]: Iterator::position
+    fn position<P: FnMut(Self::Item) -> bool>(self, predicate: P) -> Option<usize> {
+        self.into_iter().position(predicate)
+    }
+
+    /// Equivalent to [`cmp`] on [`Iterator`].
+    ///
+    /// [`cmp`]: Iterator::cmp
+    fn cmp<I: IntoIterator<Item = Self::Item>>(self, other: I) -> Ordering
+    where
+        Self::Item: Ord,
+    {
+        self.into_iter().cmp(other)
+    }
+
+    /// Similar to [`cmp`], but uses the given function to compare the items.
+    ///
+    /// This mirrors `cmp_by` on [`Iterator`], which is not yet stable.
+    ///
+    /// [`cmp`]: NonEmptyIterator::cmp
+    fn cmp_by<I: IntoIterator, F: FnMut(Self::Item, I::Item) -> Ordering>(
+        self,
+        other: I,
+        mut function: F,
+    ) -> Ordering {
+        let mut other = other.into_iter();
+
+        for item in self {
+            let Some(other_item) = other.next() else {
+                return Ordering::Greater;
+            };
+
+            match function(item, other_item) {
+                Ordering::Equal => {}
+                unequal => return unequal,
+            }
+        }
+
+        if other.next().is_some() {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Equivalent to [`partial_cmp`] on [`Iterator`].
+    ///
+    /// [`partial_cmp`]: Iterator::partial_cmp
+    fn partial_cmp<I: IntoIterator>(self, other: I) -> Option<Ordering>
+    where
+        Self::Item: PartialOrd<I::Item>,
+    {
+        self.into_iter().partial_cmp(other)
+    }
+
+    /// Equivalent to [`eq`] on [`Iterator`].
+    ///
+    /// [`eq`]: Iterator::eq
+    fn eq<I: IntoIterator>(self, other: I) -> bool
+    where
+        Self::Item: PartialEq<I::Item>,
+    {
+        self.into_iter().eq(other)
+    }
+
+    /// Similar to [`eq`], but uses the given function to check the items for equality.
+    ///
+    /// This mirrors `eq_by` on [`Iterator`], which is not yet stable.
+    ///
+    /// [`eq`]: NonEmptyIterator::eq
+    fn eq_by<I: IntoIterator, F: FnMut(Self::Item, I::Item) -> bool>(
+        self,
+        other: I,
+        mut function: F,
+    ) -> bool {
+        let mut other = other.into_iter();
+
+        for item in self {
+            let Some(other_item) = other.next() else {
+                return false;
+            };
+
+            if !function(item, other_item) {
+                return false;
+            }
+        }
+
+        other.next().is_none()
+    }
+
+    /// Equivalent to [`ne`] on [`Iterator`].
+    ///
+    /// [`ne`]: Iterator::ne
+    fn ne<I: IntoIterator>(self, other: I) -> bool
+    where
+        Self::Item: PartialEq<I::Item>,
+    {
+        self.into_iter().ne(other)
+    }
+
+    /// Equivalent to [`lt`] on [`Iterator`].
+    ///
+    /// [`lt`]: Iterator::lt
+    fn lt<I: IntoIterator>(self, other: I) -> bool
+    where
+        Self::Item: PartialOrd<I::Item>,
+    {
+        self.into_iter().lt(other)
+    }
+
+    /// Equivalent to [`le`] on [`Iterator`].
+    ///
+    /// [`le`]: Iterator::le
+    fn le<I: IntoIterator>(self, other: I) -> bool
+    where
+        Self::Item: PartialOrd<I::Item>,
+    {
+        self.into_iter().le(other)
+    }
+
+    /// Equivalent to [`gt`] on [`Iterator`].
+    ///
+    /// [`gt`]: Iterator::gt
+    fn gt<I: IntoIterator>(self, other: I) -> bool
+    where
+        Self::Item: PartialOrd<I::Item>,
+    {
+        self.into_iter().gt(other)
+    }
+
+    /// Equivalent to [`ge`] on [`Iterator`].
+    ///
+    /// [`ge`]: Iterator::ge
+    fn ge<I: IntoIterator>(self, other: I) -> bool
+    where
+        Self::Item: PartialOrd<I::Item>,
+    {
+        self.into_iter().ge(other)
+    }
+
+    /// Equivalent to [`is_sorted`] on [`Iterator`].
+    ///
+    /// [`is_sorted`]: Iterator::is_sorted
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted(self) -> bool
+    where
+        Self::Item: PartialOrd,
+    {
+        self.into_iter().is_sorted()
+    }
+
+    /// Equivalent to [`is_sorted_by`] on [`Iterator`].
+    ///
+    /// [`is_sorted_by`]: Iterator::is_sorted_by
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted_by<F: FnMut(&Self::Item, &Self::Item) -> bool>(self, function: F) -> bool {
+        self.into_iter().is_sorted_by(function)
+    }
+
+    /// Equivalent to [`is_sorted_by_key`] on [`Iterator`].
+    ///
+    /// [`is_sorted_by_key`]: Iterator::is_sorted_by_key
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted_by_key<K: PartialOrd, F: FnMut(Self::Item) -> K>(self, function: F) -> bool {
+        self.into_iter().is_sorted_by_key(function)
+    }
+
+    /// Similar to [`collect`], but extends the provided collection instead of creating new ones.
+    ///
+    /// Returns the provided collection after extending.
+    ///
+    /// [`collect`]: NonEmptyIterator::collect
+    fn collect_into<C: Extend<Self::Item>>(self, collection: &mut C) -> &mut C {
+        collection.extend(self);
+
+        collection
+    }
+
+    /// Equivalent to [`find_map`] on [`Iterator`].
+    ///
+    /// [`find_map`]: Iterator::find_map
+    fn find_map<T, F: FnMut(Self::Item) -> Option<T>>(self, function: F) -> Option<T> {
+        self.into_iter().find_map(function)
+    }
+
+    /// Similar to [`find_map`], but applies the fallback function to the last item
+    /// if the search fails.
+    ///
+    /// # Difference from [`find_map`]
+    ///
+    /// Note that the function accepts references to items, since the last item has to be kept
+    /// for the fallback. This function always returns some value, as the iterator is non-empty.
+    ///
+    /// [`find_map`]: NonEmptyIterator::find_map
+    fn find_map_or_last<T, F: FnMut(&Self::Item) -> Option<T>, G: FnOnce(Self::Item) -> T>(
+        self,
+        mut function: F,
+        fallback: G,
+    ) -> T {
+        let (mut last, rest) = self.consume();
+
+        if let Some(output) = function(&last) {
+            return output;
+        }
+
+        for item in rest {
+            if let Some(output) = function(&item) {
+                return output;
+            }
+
+            last = item;
+        }
+
+        fallback(last)
+    }
+
+    /// Similar to [`find_map`], but applies the fallback function to the first item
+    /// if the search fails.
+    ///
+    /// See also [`find_map_or_last`].
+    ///
+    /// # Difference from [`find_map`]
+    ///
+    /// Note that the function accepts references to items, since the first item has to be kept
+    /// for the fallback. This function always returns some value, as the iterator is non-empty.
+    ///
+    /// [`find_map`]: NonEmptyIterator::find_map
+    /// [`find_map_or_last`]: NonEmptyIterator::find_map_or_last
+    fn find_map_or_first<T, F: FnMut(&Self::Item) -> Option<T>, G: FnOnce(Self::Item) -> T>(
+        self,
+        mut function: F,
+        fallback: G,
+    ) -> T {
+        let (first, mut rest) = self.consume();
+
+        if let Some(output) = function(&first) {
+            return output;
+        }
+
+        rest.find_map(|item| function(&item))
+            .unwrap_or_else(|| fallback(first))
+    }
+
+    /// Fuses the non-empty iterator, ensuring that once it returns [`None`],
+    /// it will return [`None`] forever afterwards.
+    ///
+    /// See also [`fuse`] on [`Iterator`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`fuse`]: Iterator::fuse
+    fn fuse(self) -> Fuse<Self> {
+        Fuse::new(self)
+    }
+
+    /// Reverses the iteration in non-empty iterators.
+    ///
+    /// See also [`rev`] on [`Iterator`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`rev`]: Iterator::rev
+    fn rev(self) -> Rev<Self>
+    where
+        Self::IntoIter: DoubleEndedIterator,
+    {
+        Rev::new(self)
+    }
+
+    /// Repeats the non-empty iterator endlessly.
+    ///
+    /// See also [`cycle`] on [`Iterator`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`cycle`]: Iterator::cycle
+    fn cycle(self) -> Cycle<Self>
+    where
+        Self::IntoIter: Clone,
+    {
+        Cycle::new(self)
+    }
+
+    /// Repeats the non-empty iterator the given number of times.
+    ///
+    /// If the non-empty iterator is [`ExactSizeNonEmptyIterator`], so is the returned one,
+    /// with its length saturating at [`usize::MAX`].
+    ///
+    /// See also [`cycle`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator is guaranteed to be non-empty.
+    ///
+    /// [`cycle`]: NonEmptyIterator::cycle
+    fn cycle_n(self, count: Size) -> CycleN<Self>
+    where
+        Self::IntoIter: Clone,
+    {
+        CycleN::new(self, count)
+    }
+}
+
+/// Represents [`NonEmptyIterator`] that can also be consumed from the back.
+///
+/// This is similar to [`DoubleEndedIterator`], but specifically for non-empty iterators.
+///
+/// Any [`NonEmptyIterator`] with the underlying [`DoubleEndedIterator`] is also
+/// [`DoubleEndedNonEmptyIterator`].
+pub trait DoubleEndedNonEmptyIterator: NonEmptyIterator<IntoIter: DoubleEndedIterator> {
+    /// Consumes the non-empty iterator from the back, returning the last item
+    /// along with the possibly empty iterator.
+    #[must_use]
+    fn consume_back(self) -> (Self::Item, Self::IntoIter) {
+        let mut iterator = self.into_iter();
+
+        // SAFETY: the implementor guarantees the iterator is non-empty
+        let item = unsafe { iterator.next_back().unwrap_unchecked() };
+
+        (item, iterator)
+    }
+}
+
+impl<I: NonEmptyIterator<IntoIter: DoubleEndedIterator>> DoubleEndedNonEmptyIterator for I {}
+
+/// Represents [`NonEmptyIterator`] that knows its exact length.
+///
+/// This is similar to [`ExactSizeIterator`], but specifically for non-empty iterators.
+///
+/// Note that, just like with [`ExactSizeIterator`], `unsafe` code must not rely
+/// on the correctness of [`len`].
+///
+/// [`len`]: ExactSizeNonEmptyIterator::len
+#[allow(clippy::len_without_is_empty)]
+pub trait ExactSizeNonEmptyIterator: NonEmptyIterator {
+    /// Returns the exact length of the non-empty iterator.
+    ///
+    /// # Non-zero
+    ///
+    /// The returned length is guaranteed to be non-zero.
+    #[must_use]
+    fn len(&self) -> Size;
+}
+
+/// Represents types that can be created from non-empty iterators.
+///
+/// This is similar to [`FromIterator`], but specifically for non-empty iterators.
+pub trait FromNonEmptyIterator<T>: Sized {
+    /// Creates [`Self`] from the provided non-empty iterator.
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = T>>(iterable: I) -> Self;
+}
+
+/// Collects non-empty iterators into non-empty vectors,
+/// reserving the capacity for the first item and the lower bound of the rest upfront.
+#[cfg(feature = "alloc")]
+impl<T> FromNonEmptyIterator<T> for Vec<T> {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = T>>(iterable: I) -> Self {
+        let (item, rest) = iterable.into_non_empty_iter().consume();
+
+        let (lower, _) = rest.size_hint();
+
+        let mut vec = Self::with_capacity(lower.saturating_add(1));
+
+        vec.push(item);
+        vec.extend(rest);
+
+        vec
+    }
+}
+
+/// Collects non-empty iterators into non-empty boxed slices,
+/// reserving the capacity upfront the same way as for vectors.
+#[cfg(feature = "alloc")]
+impl<T> FromNonEmptyIterator<T> for Box<[T]> {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = T>>(iterable: I) -> Self {
+        Vec::from_non_empty_iter(iterable).into_boxed_slice()
+    }
+}
+
+/// Collects non-empty iterators into non-empty reference-counted slices.
+#[cfg(feature = "alloc")]
+impl<T> FromNonEmptyIterator<T> for Rc<[T]> {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = T>>(iterable: I) -> Self {
+        iterable.into_non_empty_iter().collect()
+    }
+}
+
+/// Collects non-empty iterators into non-empty atomically reference-counted slices.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> FromNonEmptyIterator<T> for Arc<[T]> {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = T>>(iterable: I) -> Self {
+        iterable.into_non_empty_iter().collect()
+    }
+}
+
+/// Represents types that can be converted into non-empty iterators.
+///
+/// This is similar to [`IntoIterator`], but specifically for non-empty iterators.
+///
+/// Any [`NonEmptyIterator`] is also [`IntoNonEmptyIterator`], akin to how any [`Iterator`]
+/// is also [`IntoIterator`].
+pub trait IntoNonEmptyIterator: IntoIterator {
+    /// What kind of [`NonEmptyIterator`] are we turning this into?
+    type IntoNonEmptyIter: NonEmptyIterator<Item = Self::Item>;
+
+    /// Converts `self` into [`NonEmptyIterator`].
+    fn into_non_empty_iter(self) -> Self::IntoNonEmptyIter;
+
+    /// Converts `self` into [`NonEmptyIterator`] and collects its items into [`Vec<T>`].
+    ///
+    /// # Non-empty
+    ///
+    /// The returned vector is guaranteed to be non-empty.
+    #[cfg(feature = "alloc")]
+    fn into_non_empty_vec(self) -> Vec<Self::Item>
+    where
+        Self: Sized,
+    {
+        self.into_non_empty_iter().collect()
+    }
+}
+
+impl<I: NonEmptyIterator> IntoNonEmptyIterator for I {
+    type IntoNonEmptyIter = Self;
+
+    fn into_non_empty_iter(self) -> Self::IntoNonEmptyIter {
+        self
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Convenience trait implemented for any type that is [`IntoIterator`],
+/// allowing to convert iterables into non-empty ones.
+pub trait TryIntoNonEmptyIterator: sealed::Sealed {
+    /// The type of the items being iterated over.
+    type Item;
+
+    /// Which kind of [`NonEmptyIterator`] are we turning this into?
+    type IntoNonEmptyIter: NonEmptyIterator<Item = Self::Item>;
+
+    /// Tries to convert `self` into [`NonEmptyIterator`].
+    ///
+    /// Returns [`None`] if `self` is empty and therefore can not be converted.
+    fn try_into_non_empty_iter(self) -> Option<Self::IntoNonEmptyIter>;
+}
+
+impl<I: IntoIterator> sealed::Sealed for I {}
+
+impl<I: IntoIterator> TryIntoNonEmptyIterator for I {
+    type Item = I::Item;
+
+    type IntoNonEmptyIter = NonEmptyAdapter<iter::Peekable<I::IntoIter>>;
+
+    fn try_into_non_empty_iter(self) -> Option<Self::IntoNonEmptyIter> {
+        let mut peekable = self.into_iter().peekable();
+
+        peekable.peek()?;
+
+        // SAFETY: `peekable` is non-empty if we reached here
+        Some(unsafe { NonEmptyAdapter::new(peekable) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{cell::Cell, cmp::Ordering, ops::ControlFlow};
+
+    #[cfg(feature = "alloc")]
+    use alloc::{rc::Rc, string::String, vec, vec::Vec};
+
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    use alloc::sync::Arc;
+
+    use non_zero_size::Size;
+
+    use super::{
+        FromNonEmptyIterator, IntoNonEmptyIterator, NonEmptyIterator, TryIntoNonEmptyIterator,
+    };
+    use crate::{
+        chain::chain,
+        min_max::MinMaxResult,
+        once::once,
+        repeat::{repeat, repeat_n},
+    };
+
+    #[test]
+    fn nth_from_end() {
+        let non_empty = [1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(
+            non_empty.clone().nth_from_end(Size::new(2).unwrap()),
+            Some(3)
+        );
+        assert_eq!(non_empty.clone().nth_from_end(Size::new(5).unwrap()), None);
+        assert_eq!(non_empty.nth_from_end(Size::MIN), Some(4));
+    }
+
+    #[cfg(feature = "nonempty")]
+    #[test]
+    fn try_collect_non_empty() {
+        let ok: [Result<i32, &str>; 2] = [Ok(1), Ok(2)];
+
+        let collected = ok
+            .try_into_non_empty_iter()
+            .unwrap()
+            .try_collect_non_empty();
+
+        assert_eq!(collected, Ok(nonempty::nonempty![1, 2]));
+
+        let err: [Result<i32, &str>; 2] = [Ok(1), Err("x")];
+
+        let collected = err
+            .try_into_non_empty_iter()
+            .unwrap()
+            .try_collect_non_empty();
+
+        assert_eq!(collected, Err("x"));
+    }
+
+    #[cfg(feature = "nonempty")]
+    #[test]
+    fn unzip_non_empty() {
+        let non_empty = [(1, 'a'), (2, 'b')].try_into_non_empty_iter().unwrap();
+
+        let (numbers, chars) = non_empty.unzip_non_empty();
+
+        assert_eq!(numbers, nonempty::nonempty![1, 2]);
+        assert_eq!(chars, nonempty::nonempty!['a', 'b']);
+    }
+
+    #[test]
+    fn reduce_while() {
+        let non_empty = [2, 3, 4, 100].try_into_non_empty_iter().unwrap();
+
+        let sum = non_empty.reduce_while(|total, item| total + item, |&total| total > 10);
+
+        assert_eq!(sum, 109);
+    }
+
+    #[test]
+    fn reduce_while_leaves_rest_unconsumed() {
+        let pulled = Cell::new(0);
+
+        let non_empty = [5, 6, 7, 8].try_into_non_empty_iter().unwrap();
+
+        let sum = non_empty
+            .inspect(|_| pulled.set(pulled.get() + 1))
+            .reduce_while(|total, item| total + item, |&total| total > 10);
+
+        assert_eq!(sum, 11);
+        assert_eq!(pulled.get(), 2);
+    }
+
+    #[cfg(feature = "allocator_api")]
+    #[test]
+    fn collect_vec_in() {
+        use alloc::alloc::Global;
+
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let vec = non_empty.collect_vec_in(Global);
+
+        assert_eq!(vec, [1, 2, 3]);
+    }
+
+    #[test]
+    fn map_collect() {
+        struct Sum(i32);
+
+        impl FromNonEmptyIterator<i32> for Sum {
+            fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = i32>>(iterable: I) -> Self {
+                Self(iterable.into_non_empty_iter().into_iter().sum())
+            }
+        }
+
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let Sum(sum) = non_empty.map_collect(|item| item + 1);
+
+        assert_eq!(sum, 9);
+    }
+
+    #[test]
+    fn min_max_eq() {
+        assert_eq!(once(5).min_max_eq(), MinMaxResult::OneElement(5));
+
+        let non_empty = [3, 1, 2].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.min_max_eq(), MinMaxResult::MinMax(1, 3));
+
+        let non_empty = [4, 4, 4].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.min_max_eq(), MinMaxResult::OneElement(4));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn collect_boxed_slice() {
+        let boxed = chain(once(1), [2, 3]).collect_boxed_slice();
+
+        assert_eq!(boxed.len(), 3);
+        assert_eq!(*boxed, [1, 2, 3]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn collect_rc_slice() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let rc = non_empty.collect_rc_slice();
+
+        assert_eq!(*rc, [1, 2, 3]);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[test]
+    fn collect_arc_slice() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let arc = non_empty.collect_arc_slice();
+
+        assert_eq!(*arc, [1, 2, 3]);
+        assert_eq!(Arc::strong_count(&arc), 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn reduce_mut() {
+        let non_empty = [vec![1], vec![2, 3], vec![4]]
+            .try_into_non_empty_iter()
+            .unwrap();
+
+        let merged = non_empty.reduce_mut(|merged, vec| merged.extend(vec));
+
+        assert_eq!(merged, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn into_non_empty_parts() {
+        let (item, rest) = once(1).into_non_empty_parts();
+
+        assert_eq!(item, 1);
+        assert!(rest.is_none());
+
+        let (item, rest) = chain(once(1), [2, 3]).into_non_empty_parts();
+
+        assert_eq!(item, 1);
+        assert!(rest.unwrap().eq([2, 3]));
+    }
+
+    #[test]
+    fn take_saturating() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let (take, actual) = non_empty.take_saturating(Size::new(10).unwrap());
+
+        assert_eq!(actual, Size::new(3).unwrap());
+        assert!(take.into_iter().eq([1, 2, 3]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn scan_collect() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let (sums, total): (Vec<_>, _) = non_empty.scan_collect(0, |sum, item| {
+            *sum += item;
+
+            *sum
+        });
+
+        assert_eq!(sums, [1, 3, 6]);
+        assert_eq!(total, 6);
+    }
+
+    #[test]
+    fn collect_into_array() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let (array, filled) = non_empty.collect_into_array::<4>();
+
+        assert_eq!(array, [Some(1), Some(2), Some(3), None]);
+        assert_eq!(filled, Size::new(3).unwrap());
+
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let (array, filled) = non_empty.collect_into_array::<2>();
+
+        assert_eq!(array, [Some(1), Some(2)]);
+        assert_eq!(filled, Size::new(2).unwrap());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fold_map() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let (sum, doubled): (_, Vec<_>) = non_empty.fold_map(0, |sum, item| {
+            *sum += item;
+
+            item * 2
+        });
+
+        assert_eq!(sum, 6);
+        assert_eq!(doubled, [2, 4, 6]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn max_set() {
+        let non_empty = [3, 1, 3, 2, 3].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.max_set(), [3, 3, 3]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn min_set() {
+        let non_empty = [3, 1, 3, 1, 2].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.min_set(), [1, 1]);
+    }
+
+    #[test]
+    fn reduce_counted() {
+        let non_empty = [10, 20, 30].try_into_non_empty_iter().unwrap();
+
+        let (sum, count) = non_empty.reduce_counted(|total, item| total + item);
+
+        assert_eq!((sum, count), (60, Size::new(3).unwrap()));
+        assert_eq!(sum / count.get(), 20);
+    }
+
+    #[test]
+    fn step_by_offset() {
+        let non_empty = [0, 1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        let stepped = non_empty.step_by_offset(Size::MIN, Size::new(2).unwrap());
+
+        assert!(stepped.eq([1, 3]));
+
+        let non_empty = [0, 1, 2].try_into_non_empty_iter().unwrap();
+
+        let mut stepped = non_empty.step_by_offset(Size::new(5).unwrap(), Size::MIN);
+
+        assert_eq!(stepped.next(), None);
+    }
+
+    #[test]
+    fn find_map_or_last() {
+        let non_empty = [1, 3, 5].try_into_non_empty_iter().unwrap();
+
+        let found = non_empty
+            .clone()
+            .find_map_or_last(|&item| (item > 2).then_some(item * 10), |item| -item);
+
+        assert_eq!(found, 30);
+
+        let found =
+            non_empty.find_map_or_last(|&item| (item > 5).then_some(item * 10), |item| -item);
+
+        assert_eq!(found, -5);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn reduce_by_key() {
+        let non_empty = [(1, 10), (2, 5), (1, 3)].try_into_non_empty_iter().unwrap();
+
+        let reduced = non_empty.reduce_by_key(|total, value| total + value);
+
+        assert_eq!(reduced.len(), 2);
+        assert_eq!(reduced[&1], 13);
+        assert_eq!(reduced[&2], 5);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn split_at() {
+        let non_empty = [1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        let (prefix, rest) = non_empty.clone().split_at(Size::new(2).unwrap());
+
+        assert_eq!(prefix, vec![1, 2]);
+        assert!(rest.eq([3, 4]));
+
+        let (prefix, mut rest) = non_empty.split_at(Size::new(5).unwrap());
+
+        assert_eq!(prefix, vec![1, 2, 3, 4]);
+        assert_eq!(rest.next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn positions_max() {
+        let non_empty = [1, 3, 2, 3].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.positions_max(), vec![1, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn collect_map_first_wins() {
+        let non_empty = [(1, 'a'), (1, 'b'), (2, 'c')]
+            .try_into_non_empty_iter()
+            .unwrap();
+
+        let map = non_empty.collect_map_first_wins();
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&1], 'a');
+        assert_eq!(map[&2], 'c');
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn collect_btree_map() {
+        let non_empty = [(2, 'b'), (1, 'a')].try_into_non_empty_iter().unwrap();
+
+        let map = non_empty.collect_btree_map();
+
+        assert!(map.into_iter().eq([(1, 'a'), (2, 'b')]));
+    }
+
+    #[test]
+    fn sum_saturating() {
+        let non_empty = [200u8, 100, 50].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.sum_saturating(), u8::MAX);
+
+        let non_empty = [-100i8, -100].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.sum_saturating(), i8::MIN);
+    }
+
+    #[test]
+    fn push_back() {
+        assert!(once(1).push_back(2).eq([1, 2]));
+        assert!(once(1).push_back(2).push_back(3).eq([1, 2, 3]));
+    }
+
+    #[test]
+    fn push_front() {
+        let pushed = once(2).push_front(1);
+
+        assert_eq!(pushed.peek(), &1);
+        assert!(pushed.eq([1, 2]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn try_map() {
+        let non_empty = ["1", "2", "3"].try_into_non_empty_iter().unwrap();
+
+        let parsed = non_empty.try_map(str::parse::<i32>).unwrap();
+
+        assert!(parsed.eq([1, 2, 3]));
+
+        let non_empty = ["1", "x", "3"].try_into_non_empty_iter().unwrap();
+
+        assert!(non_empty.try_map(str::parse::<i32>).is_err());
+    }
+
+    #[test]
+    fn count_where() {
+        let non_empty = [1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.clone().count_where(|item| item % 2 == 0), 2);
+
+        assert_eq!(
+            non_empty
+                .clone()
+                .count_where_at_least_one(|item| item % 2 == 0),
+            Size::new(2)
+        );
+
+        assert_eq!(non_empty.count_where_at_least_one(|item| *item > 4), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn flatten_iter() {
+        let nested = chain(once(vec![1]), [vec![], vec![2, 3]]);
+
+        assert!(nested.flatten_iter().eq([1, 2, 3]));
+    }
+
+    #[test]
+    fn reduce_chain() {
+        let non_empty = chain(once(0u64), 1..1000);
+
+        assert_eq!(non_empty.reduce(|total, item| total + item), 499_500);
+    }
+
+    #[test]
+    fn min_by_cached_key() {
+        let calls = Cell::new(0);
+
+        let non_empty = [3, -1, 2, 1].try_into_non_empty_iter().unwrap();
+
+        let min = non_empty.min_by_cached_key(|item: &i32| {
+            calls.set(calls.get() + 1);
+
+            item.abs()
+        });
+
+        assert_eq!(min, -1);
+        assert_eq!(calls.get(), 4);
+    }
+
+    #[test]
+    fn oks_errs() {
+        let results: [Result<i32, &str>; 3] = [Ok(1), Err("e"), Ok(3)];
+
+        let non_empty = results.try_into_non_empty_iter().unwrap();
+
+        assert!(non_empty.clone().oks().eq([1, 3]));
+        assert!(non_empty.errs().eq(["e"]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn collect_separated() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(
+            non_empty.clone().collect_separated(",", Some(";")),
+            "1,2,3;"
+        );
+        assert_eq!(non_empty.collect_separated("", None), "123");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn into_non_empty_vec() {
+        let non_empty = chain(once(1), [2, 3]);
+
+        assert_eq!(non_empty.into_non_empty_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn max_min_ties() {
+        #[derive(Debug, Clone, Copy)]
+        struct Keyed(i32, char);
+
+        impl PartialEq for Keyed {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for Keyed {}
+
+        impl PartialOrd for Keyed {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Keyed {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let non_empty = [Keyed(1, 'a'), Keyed(1, 'b')]
+            .try_into_non_empty_iter()
+            .unwrap();
+
+        assert_eq!(non_empty.clone().max_first().1, 'a');
+        assert_eq!(non_empty.clone().max_last().1, 'b');
+        assert_eq!(non_empty.clone().min_first().1, 'a');
+        assert_eq!(non_empty.min_last().1, 'b');
+    }
+
+    #[test]
+    fn count_up_to() {
+        let repeated = repeat_n(0, Size::new(3).unwrap());
+
+        assert_eq!(repeated.count_up_to(Size::new(5).unwrap()), Size::new(3));
+
+        assert_eq!(repeat(0).count_up_to(Size::new(5).unwrap()), None);
+    }
+
+    #[test]
+    fn reduce_lanes() {
+        let non_empty = chain(once(1), 2..=8);
+
+        let sum = non_empty.reduce_lanes::<4, _, _>(
+            |lane| lane.into_iter().sum(),
+            |total, partial| total + partial,
+        );
+
+        assert_eq!(sum, 36);
+
+        let non_empty = chain(once(1), 2..=6);
+
+        let sum = non_empty.reduce_lanes::<4, _, _>(
+            |lane| lane.into_iter().sum(),
+            |total, partial| total + partial,
+        );
+
+        assert_eq!(sum, 21);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn debug_items() {
+        struct Traced<'f> {
+            value: i32,
+            formats: &'f Cell<usize>,
+        }
+
+        impl core::fmt::Debug for Traced<'_> {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.formats.set(self.formats.get() + 1);
+
+                self.value.fmt(formatter)
+            }
+        }
+
+        let formats = Cell::new(0);
+
+        let non_empty = [1, 2, 3]
+            .map(|value| Traced {
+                value,
+                formats: &formats,
+            })
+            .try_into_non_empty_iter()
+            .unwrap();
+
+        let values = non_empty.debug_items("item").map(|traced| traced.value);
+
+        assert!(values.eq([1, 2, 3]));
+        assert_eq!(formats.get(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn collect_string_with_capacity() {
+        let non_empty = ["ab", "cd"].try_into_non_empty_iter().unwrap();
+
+        let string = non_empty.collect_string_with_capacity(8);
+
+        assert_eq!(string, "abcd");
+        assert_eq!(string.capacity(), String::with_capacity(8).capacity());
+    }
+
+    #[test]
+    fn nth_or_last() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.clone().nth_or_last(Size::new(2).unwrap()), 2);
+        assert_eq!(non_empty.nth_or_last(Size::new(10).unwrap()), 3);
+    }
+
+    #[test]
+    fn sum_count() {
+        let non_empty = [1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.sum_count(), Size::new(4).unwrap());
+    }
+
+    #[test]
+    fn reduce_plurality() {
+        assert_eq!(once(5).reduce_plurality(|a, b| a + b), (5, false));
+        assert_eq!(
+            chain(once(1), [2]).reduce_plurality(|a, b| a + b),
+            (3, true)
+        );
+    }
+
+    #[test]
+    fn cmp_by() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(
+            non_empty.clone().cmp_by([1, 2, 4], |a, b| a.cmp(&b)),
+            Ordering::Less
+        );
+
+        assert_eq!(
+            non_empty.clone().cmp_by([1, 2, 4], |a, b| b.cmp(&a)),
+            Ordering::Greater
+        );
+
+        assert_eq!(
+            non_empty.cmp_by([1, 2], |a, b| a.cmp(&b)),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn eq_by() {
+        let non_empty = ["A", "B"].try_into_non_empty_iter().unwrap();
+
+        assert!(
+            non_empty
+                .clone()
+                .eq_by(["a", "b"], |a, b| a.eq_ignore_ascii_case(b))
+        );
+
+        assert!(!non_empty.clone().eq_by(["a", "b"], |a, b| a == b));
+
+        assert!(!non_empty.eq_by(["a"], |a, b| a.eq_ignore_ascii_case(b)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn reduce_extend() {
+        let first = Vec::with_capacity(3);
+
+        let pointer = first.as_ptr();
+
+        let non_empty = chain(once(first), [vec![1], vec![2, 3]]);
+
+        let reduced = non_empty.reduce_extend();
+
+        assert_eq!(reduced, vec![1, 2, 3]);
+        assert_eq!(reduced.as_ptr(), pointer);
+    }
+
+    #[test]
+    fn step_by_exceeding_length() {
+        let stepped = chain(once(1), [2, 3]).step_by(Size::new(100).unwrap());
+
+        assert!(stepped.eq([1]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn split_while() {
+        let non_empty = [1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        let (prefix, rest) = non_empty.split_while(|item| *item < 3);
+
+        assert_eq!(prefix, vec![1, 2]);
+        assert!(rest.eq([3, 4]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn histogram() {
+        let non_empty = [0u8, 1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.histogram(Size::new(2).unwrap()), vec![3, 2]);
+    }
+
+    #[test]
+    fn reduce_while_absorbing() {
+        let pulled = Cell::new(0);
+
+        let non_empty = [2, 3, 0, 4]
+            .try_into_non_empty_iter()
+            .unwrap()
+            .inspect(|_| pulled.set(pulled.get() + 1));
+
+        let product = non_empty.reduce_while(|product, item| product * item, |x| *x == 0);
+
+        assert_eq!(product, 0);
+
+        // the `4` is never pulled, let alone multiplied
+        assert_eq!(pulled.get(), 3);
+    }
+
+    #[test]
+    fn min_max_positions() {
+        let non_empty = [3, 1, 4, 1, 5].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(non_empty.min_max_positions(), ((1, 1), (4, 5)));
+    }
+
+    #[test]
+    fn sum_kahan() {
+        let large = 1e16;
+
+        let non_empty = chain(once(large), core::iter::repeat_n(1.0, 1000));
+
+        let naive: f64 = non_empty.clone().sum();
+        let compensated = non_empty.sum_kahan();
+
+        let expected = large + 1000.0;
+
+        assert_eq!(compensated, expected);
+        assert!((compensated - expected).abs() < (naive - expected).abs());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn collect_non_empty_vec_capacity() {
+        let vec: Vec<i32> = repeat_n(0, Size::new(1000).unwrap()).collect_non_empty();
+
+        assert_eq!(vec.len(), 1000);
+        assert_eq!(vec.capacity(), 1000);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn scan_final() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let (sums, total) = non_empty.scan_final(0, |total, item| {
+            *total += item;
+
+            *total
+        });
+
+        assert_eq!(sums, vec![1, 3, 6]);
+        assert_eq!(total, 6);
+    }
+
+    #[test]
+    fn find_map_or_first() {
+        let non_empty = [1, 3, 5].try_into_non_empty_iter().unwrap();
+
+        let found = non_empty
+            .clone()
+            .find_map_or_first(|&item| (item > 2).then_some(item * 10), |item| -item);
+
+        assert_eq!(found, 30);
+
+        let found =
+            non_empty.find_map_or_first(|&item| (item > 5).then_some(item * 10), |item| -item);
+
+        assert_eq!(found, -1);
+    }
+
+    #[test]
+    fn fold_control() {
+        let non_empty = [1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        let sum = |total: i32, item| {
+            let total = total + item;
+
+            if total > 5 {
+                ControlFlow::Break(total)
+            } else {
+                ControlFlow::Continue(total)
+            }
+        };
+
+        assert_eq!(
+            non_empty.clone().fold_control(0, sum),
+            ControlFlow::Break(6)
+        );
+        assert_eq!(non_empty.fold_control(-10, sum), ControlFlow::Continue(0));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn zip_map_collect() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+        let other = [4, 5, 6].try_into_non_empty_iter().unwrap();
+
+        let products: Vec<_> = non_empty.zip_map_collect(other, |a, b| a * b);
+
+        assert_eq!(products, vec![4, 10, 18]);
     }
 }
@@ -1,8 +1,13 @@
 //! Yielding items exactly once.
 
-use core::iter;
+use core::{iter, slice};
 
-use crate::non_empty::NonEmptyIterator;
+use non_zero_size::Size;
+
+use crate::{
+    non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator},
+    slice::AsNonEmptySlice,
+};
 
 /// Creates [`Once<T>`], non-empty iterator that yields the given value exactly once.
 pub const fn once<T>(value: T) -> Once<T> {
@@ -43,6 +48,22 @@ impl<T> IntoIterator for Once<T> {
 
 unsafe impl<T> NonEmptyIterator for Once<T> {}
 
+impl<T> ExactSizeNonEmptyIterator for Once<T> {
+    fn len(&self) -> Size {
+        // SAFETY: one is non-zero
+        unsafe { Size::new_unchecked(1) }
+    }
+}
+
+// SAFETY: the slice contains exactly one element
+unsafe impl<T> AsNonEmptySlice for Once<T> {
+    type Element = T;
+
+    fn as_non_empty_slice(&self) -> &[Self::Element] {
+        slice::from_ref(&self.value)
+    }
+}
+
 /// Represents non-empty iterators that yield the value computed from
 /// the given function exactly once.
 ///
@@ -71,3 +92,10 @@ impl<T, F: FnOnce() -> T> IntoIterator for OnceWith<F> {
 }
 
 unsafe impl<T, F: FnOnce() -> T> NonEmptyIterator for OnceWith<F> {}
+
+impl<T, F: FnOnce() -> T> ExactSizeNonEmptyIterator for OnceWith<F> {
+    fn len(&self) -> Size {
+        // SAFETY: one is non-zero
+        unsafe { Size::new_unchecked(1) }
+    }
+}
@@ -0,0 +1,140 @@
+//! Padding non-empty iterators to the minimum length.
+
+use non_zero_size::Size;
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that yield copies of the fill item after the underlying
+/// non-empty iterator is exhausted, until the minimum length is reached.
+///
+/// This `struct` is created by the [`pad_to`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`pad_to`]: NonEmptyIterator::pad_to
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct PadTo<I: NonEmptyIterator>
+where
+    I::Item: Clone,
+{
+    non_empty: I,
+    len: Size,
+    fill: I::Item,
+}
+
+impl<I: NonEmptyIterator> PadTo<I>
+where
+    I::Item: Clone,
+{
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, len: Size, fill: I::Item) -> Self {
+        Self {
+            non_empty,
+            len,
+            fill,
+        }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for PadTo<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    type IntoIter = PadToIter<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PadToIter::new(self.non_empty.into_iter(), self.len.get(), self.fill)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for PadTo<I> where I::Item: Clone {}
+
+/// Represents iterators that yield copies of the fill item after the underlying iterator
+/// is exhausted, until the minimum length is reached.
+///
+/// This `struct` is created by the [`into_iter`] method on [`PadTo`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PadToIter<I: Iterator>
+where
+    I::Item: Clone,
+{
+    iterator: I,
+    remaining: usize,
+    fill: I::Item,
+}
+
+impl<I: Iterator> PadToIter<I>
+where
+    I::Item: Clone,
+{
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, remaining: usize, fill: I::Item) -> Self {
+        Self {
+            iterator,
+            remaining,
+            fill,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for PadToIter<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.iterator.next() {
+            self.remaining = self.remaining.saturating_sub(1);
+
+            return Some(item);
+        }
+
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+
+        Some(self.fill.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iterator.size_hint();
+
+        (
+            lower.max(self.remaining),
+            upper.map(|upper| upper.max(self.remaining)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn pad_to_longer() {
+        let non_empty = [1, 2].try_into_non_empty_iter().unwrap();
+
+        let padded = non_empty.pad_to(Size::new(4).unwrap(), 0).into_iter();
+
+        assert!(padded.eq([1, 2, 0, 0]));
+    }
+
+    #[test]
+    fn pad_to_shorter() {
+        let non_empty = [1, 2].try_into_non_empty_iter().unwrap();
+
+        let padded = non_empty.pad_to(Size::MIN, 0).into_iter();
+
+        assert!(padded.eq([1, 2]));
+    }
+}
@@ -2,7 +2,9 @@
 
 use core::iter;
 
-use crate::non_empty::NonEmptyIterator;
+use non_zero_size::Size;
+
+use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
 
 /// Represents non-empty peeked iterators.
 ///
@@ -50,3 +52,12 @@ impl<I: Iterator> IntoIterator for Peeked<I> {
 }
 
 unsafe impl<I: Iterator> NonEmptyIterator for Peeked<I> {}
+
+impl<I: ExactSizeIterator> ExactSizeNonEmptyIterator for Peeked<I> {
+    fn len(&self) -> Size {
+        let len = self.rest.len().saturating_add(1);
+
+        // SAFETY: the addition saturates, therefore `len` is non-zero
+        unsafe { Size::new_unchecked(len) }
+    }
+}
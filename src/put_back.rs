@@ -0,0 +1,129 @@
+//! Non-empty iterators that allow putting items back.
+
+use alloc::vec::Vec;
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that allow putting items back,
+/// so that they are yielded before the rest of the items.
+///
+/// This `struct` is created by the [`into_put_back`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`into_put_back`]: NonEmptyIterator::into_put_back
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct PutBack<I: NonEmptyIterator> {
+    non_empty: I,
+    buffer: Vec<I::Item>,
+}
+
+impl<I: NonEmptyIterator> PutBack<I> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I) -> Self {
+        Self {
+            non_empty,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Puts the item back, so that it is yielded next.
+    ///
+    /// The items that were put back are yielded in the reverse order.
+    pub fn put_back(&mut self, item: I::Item) {
+        self.buffer.push(item);
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for PutBack<I> {
+    type Item = I::Item;
+
+    type IntoIter = PutBackIter<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PutBackIter::with_buffer(self.non_empty.into_iter(), self.buffer)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for PutBack<I> {}
+
+/// Represents iterators that allow putting items back,
+/// so that they are yielded before the rest of the items.
+///
+/// This `struct` is created by the [`into_iter`] method on [`PutBack`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PutBackIter<I: Iterator> {
+    iterator: I,
+    buffer: Vec<I::Item>,
+}
+
+impl<I: Iterator> PutBackIter<I> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I) -> Self {
+        Self::with_buffer(iterator, Vec::new())
+    }
+
+    /// Constructs [`Self`] with the items that were put back, the last one being yielded first.
+    pub const fn with_buffer(iterator: I, buffer: Vec<I::Item>) -> Self {
+        Self { iterator, buffer }
+    }
+
+    /// Puts the item back, so that it is yielded next.
+    ///
+    /// The items that were put back are yielded in the reverse order.
+    pub fn put_back(&mut self, item: I::Item) {
+        self.buffer.push(item);
+    }
+}
+
+impl<I: Iterator> Iterator for PutBackIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.pop().or_else(|| self.iterator.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffer.len();
+
+        let (lower, upper) = self.iterator.size_hint();
+
+        let lower = lower.saturating_add(buffered);
+        let upper = upper.and_then(|upper| upper.checked_add(buffered));
+
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn put_back_during_iteration() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        let mut iterator = non_empty.into_put_back().into_iter();
+
+        assert_eq!(iterator.next(), Some(1));
+        assert_eq!(iterator.next(), Some(2));
+
+        iterator.put_back(2);
+
+        assert!(iterator.eq([2, 3]));
+    }
+
+    #[test]
+    fn put_back_before_iteration() {
+        let non_empty = [1, 2].try_into_non_empty_iter().unwrap();
+
+        let mut put_back = non_empty.into_put_back();
+
+        put_back.put_back(0);
+
+        assert!(put_back.into_iter().eq([0, 1, 2]));
+    }
+}
@@ -0,0 +1,140 @@
+//! Reducing segments of non-empty iterators delimited by boundaries.
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that reduce each segment of items into the single one,
+/// where the segments start at the boundary items.
+///
+/// This `struct` is created by the [`reduce_segments`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`reduce_segments`]: NonEmptyIterator::reduce_segments
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct ReduceSegments<I: NonEmptyIterator, F, P> {
+    non_empty: I,
+    combine: F,
+    is_boundary: P,
+}
+
+impl<I: NonEmptyIterator, F, P> ReduceSegments<I, F, P> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, combine: F, is_boundary: P) -> Self {
+        Self {
+            non_empty,
+            combine,
+            is_boundary,
+        }
+    }
+}
+
+impl<I, F, P> IntoIterator for ReduceSegments<I, F, P>
+where
+    I: NonEmptyIterator,
+    F: FnMut(I::Item, I::Item) -> I::Item,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    type IntoIter = ReduceSegmentsIter<I::IntoIter, F, P>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ReduceSegmentsIter::new(self.non_empty.into_iter(), self.combine, self.is_boundary)
+    }
+}
+
+unsafe impl<I, F, P> NonEmptyIterator for ReduceSegments<I, F, P>
+where
+    I: NonEmptyIterator,
+    F: FnMut(I::Item, I::Item) -> I::Item,
+    P: FnMut(&I::Item) -> bool,
+{
+}
+
+/// Represents iterators that reduce each segment of items into the single one,
+/// where the segments start at the boundary items.
+///
+/// This `struct` is created by the [`into_iter`] method on [`ReduceSegments`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ReduceSegmentsIter<I: Iterator, F, P> {
+    iterator: I,
+    combine: F,
+    is_boundary: P,
+    boundary: Option<I::Item>,
+}
+
+impl<I: Iterator, F, P> ReduceSegmentsIter<I, F, P> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, combine: F, is_boundary: P) -> Self {
+        Self {
+            iterator,
+            combine,
+            is_boundary,
+            boundary: None,
+        }
+    }
+}
+
+impl<I, F, P> Iterator for ReduceSegmentsIter<I, F, P>
+where
+    I: Iterator,
+    F: FnMut(I::Item, I::Item) -> I::Item,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the first item always starts the segment, even if it is not the boundary
+        let mut output = self.boundary.take().or_else(|| self.iterator.next())?;
+
+        for item in self.iterator.by_ref() {
+            if (self.is_boundary)(&item) {
+                self.boundary = Some(item);
+
+                break;
+            }
+
+            output = (self.combine)(output, item);
+        }
+
+        Some(output)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let boundary = usize::from(self.boundary.is_some());
+
+        let (lower, upper) = self.iterator.size_hint();
+
+        let lower = usize::from(lower > 0 || boundary > 0);
+        let upper = upper.and_then(|upper| upper.checked_add(boundary));
+
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn reduce_segments_sum() {
+        // the zero marks the boundary, and does not change the sums
+        let non_empty = [1, 2, 0, 3, 4].try_into_non_empty_iter().unwrap();
+
+        let sums = non_empty.reduce_segments(|total, item| total + item, |item| *item == 0);
+
+        assert!(sums.eq([3, 7]));
+    }
+
+    #[test]
+    fn reduce_segments_all_boundaries() {
+        let non_empty = [0, 0].try_into_non_empty_iter().unwrap();
+
+        let sums = non_empty.reduce_segments(|total, item| total + item, |item| *item == 0);
+
+        assert!(sums.eq([0, 0]));
+    }
+}
@@ -4,7 +4,7 @@ use core::iter;
 
 use non_zero_size::Size;
 
-use crate::non_empty::NonEmptyIterator;
+use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
 
 /// Creates [`Repeat<T>`] non-empty iterator that repeats the given item endlessly.
 pub const fn repeat<T: Clone>(item: T) -> Repeat<T> {
@@ -93,6 +93,36 @@ impl<T: Clone> RepeatN<T> {
     pub const fn new(item: T, count: Size) -> Self {
         Self { item, count }
     }
+
+    /// Returns the reference to the maximum item, which is the repeated item itself.
+    ///
+    /// This mirrors [`max_ref`] on [`AsNonEmptySlice`], which [`RepeatN<T>`] can not implement,
+    /// as the repeated items are not stored in any slice.
+    ///
+    /// [`max_ref`]: crate::slice::AsNonEmptySlice::max_ref
+    /// [`AsNonEmptySlice`]: crate::slice::AsNonEmptySlice
+    #[must_use]
+    pub const fn max_ref(&self) -> &T
+    where
+        T: Ord,
+    {
+        &self.item
+    }
+
+    /// Returns the reference to the minimum item, which is the repeated item itself.
+    ///
+    /// This mirrors [`min_ref`] on [`AsNonEmptySlice`], which [`RepeatN<T>`] can not implement,
+    /// as the repeated items are not stored in any slice.
+    ///
+    /// [`min_ref`]: crate::slice::AsNonEmptySlice::min_ref
+    /// [`AsNonEmptySlice`]: crate::slice::AsNonEmptySlice
+    #[must_use]
+    pub const fn min_ref(&self) -> &T
+    where
+        T: Ord,
+    {
+        &self.item
+    }
 }
 
 impl<T: Clone> IntoIterator for RepeatN<T> {
@@ -106,3 +136,84 @@ impl<T: Clone> IntoIterator for RepeatN<T> {
 }
 
 unsafe impl<T: Clone> NonEmptyIterator for RepeatN<T> {}
+
+impl<T: Clone> ExactSizeNonEmptyIterator for RepeatN<T> {
+    fn len(&self) -> Size {
+        self.count
+    }
+}
+
+/// Creates [`RepeatWithN<F>`] non-empty iterator that repeats items
+/// computed from the given function the given number of times.
+pub const fn repeat_with_n<T, F: FnMut() -> T>(function: F, count: Size) -> RepeatWithN<F> {
+    RepeatWithN::new(function, count)
+}
+
+/// Represents non-empty iterators that repeat items computed from the given function
+/// exactly the given number of times.
+///
+/// This `struct` is created by the [`repeat_with_n`] function. See its documentation for more.
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct RepeatWithN<F> {
+    function: F,
+    count: Size,
+}
+
+impl<F> RepeatWithN<F> {
+    /// Constructs [`Self`].
+    pub const fn new(function: F, count: Size) -> Self {
+        Self { function, count }
+    }
+}
+
+impl<T, F: FnMut() -> T> IntoIterator for RepeatWithN<F> {
+    type Item = T;
+
+    type IntoIter = iter::Take<iter::RepeatWith<F>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iter::repeat_with(self.function).take(self.count.get())
+    }
+}
+
+unsafe impl<T, F: FnMut() -> T> NonEmptyIterator for RepeatWithN<F> {}
+
+impl<T, F: FnMut() -> T> ExactSizeNonEmptyIterator for RepeatWithN<F> {
+    fn len(&self) -> Size {
+        self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use super::{repeat_n, repeat_with_n};
+    use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
+
+    #[test]
+    fn repeat_with_n_counter() {
+        let mut counter = 0;
+
+        let repeated = repeat_with_n(
+            || {
+                counter += 1;
+
+                counter
+            },
+            Size::new(3).unwrap(),
+        );
+
+        assert_eq!(repeated.len().get(), 3);
+        assert!(repeated.eq([1, 2, 3]));
+    }
+
+    #[test]
+    fn repeat_n_max_min_ref() {
+        let repeated = repeat_n(7, Size::new(3).unwrap());
+
+        assert_eq!(repeated.max_ref(), &7);
+        assert_eq!(repeated.min_ref(), &7);
+    }
+}
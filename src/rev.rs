@@ -27,6 +27,15 @@ where
     pub const fn new(non_empty: I) -> Self {
         Self { non_empty }
     }
+
+    /// Reverses the iteration back, returning the underlying non-empty iterator.
+    ///
+    /// Unlike calling [`rev`] again, this does not wrap the iterator into another [`Rev`].
+    ///
+    /// [`rev`]: NonEmptyIterator::rev
+    pub fn unrev(self) -> I {
+        self.non_empty
+    }
 }
 
 impl<I: NonEmptyIterator> IntoIterator for Rev<I>
@@ -43,3 +52,26 @@ where
 }
 
 unsafe impl<I: NonEmptyIterator> NonEmptyIterator for Rev<I> where I::IntoIter: DoubleEndedIterator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator},
+        once::{Once, once},
+    };
+
+    #[test]
+    fn rev_rev() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        assert!(non_empty.clone().rev().rev().eq(non_empty.clone()));
+        assert!(non_empty.clone().rev().unrev().eq(non_empty));
+    }
+
+    #[test]
+    fn unrev_type() {
+        let unreversed: Once<i32> = once(1).rev().unrev();
+
+        assert!(unreversed.eq([1]));
+    }
+}
@@ -0,0 +1,71 @@
+//! Rotating non-empty iterators to the left.
+
+use core::iter;
+
+use alloc::vec::{self, Vec};
+
+use non_zero_size::Size;
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that yield the first given number of items
+/// after the rest of the items.
+///
+/// This `struct` is created by the [`rotate_left`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`rotate_left`]: NonEmptyIterator::rotate_left
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct RotateLeft<I: NonEmptyIterator> {
+    non_empty: I,
+    mid: Size,
+}
+
+impl<I: NonEmptyIterator> RotateLeft<I> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, mid: Size) -> Self {
+        Self { non_empty, mid }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for RotateLeft<I> {
+    type Item = I::Item;
+
+    type IntoIter = iter::Chain<I::IntoIter, vec::IntoIter<I::Item>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut iterator = self.non_empty.into_iter();
+
+        let buffer: Vec<_> = iterator.by_ref().take(self.mid.get()).collect();
+
+        iterator.chain(buffer)
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for RotateLeft<I> {}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn rotate_left() {
+        let non_empty = [1, 2, 3, 4, 5].try_into_non_empty_iter().unwrap();
+
+        let rotated = non_empty.rotate_left(Size::new(2).unwrap()).into_iter();
+
+        assert!(rotated.eq([3, 4, 5, 1, 2]));
+    }
+
+    #[test]
+    fn rotate_left_full() {
+        let non_empty = [1, 2, 3, 4, 5].try_into_non_empty_iter().unwrap();
+
+        let rotated = non_empty.rotate_left(Size::new(5).unwrap()).into_iter();
+
+        assert!(rotated.eq([1, 2, 3, 4, 5]));
+    }
+}
@@ -0,0 +1,206 @@
+//! Running minimum and maximum items of non-empty iterators.
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that yield the minimum item seen so far at each step.
+///
+/// This `struct` is created by the [`running_min`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`running_min`]: NonEmptyIterator::running_min
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct RunningMin<I: NonEmptyIterator>
+where
+    I::Item: Ord + Clone,
+{
+    non_empty: I,
+}
+
+impl<I: NonEmptyIterator> RunningMin<I>
+where
+    I::Item: Ord + Clone,
+{
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I) -> Self {
+        Self { non_empty }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for RunningMin<I>
+where
+    I::Item: Ord + Clone,
+{
+    type Item = I::Item;
+
+    type IntoIter = RunningMinIter<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RunningMinIter::new(self.non_empty.into_iter())
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for RunningMin<I> where I::Item: Ord + Clone {}
+
+/// Represents iterators that yield the minimum item seen so far at each step.
+///
+/// This `struct` is created by the [`into_iter`] method on [`RunningMin`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RunningMinIter<I: Iterator>
+where
+    I::Item: Ord + Clone,
+{
+    iterator: I,
+    min: Option<I::Item>,
+}
+
+impl<I: Iterator> RunningMinIter<I>
+where
+    I::Item: Ord + Clone,
+{
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I) -> Self {
+        Self {
+            iterator,
+            min: None,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for RunningMinIter<I>
+where
+    I::Item: Ord + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next()?;
+
+        let min = match self.min.take() {
+            Some(min) => min.min(item),
+            None => item,
+        };
+
+        self.min = Some(min.clone());
+
+        Some(min)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+/// Represents non-empty iterators that yield the maximum item seen so far at each step.
+///
+/// This `struct` is created by the [`running_max`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`running_max`]: NonEmptyIterator::running_max
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct RunningMax<I: NonEmptyIterator>
+where
+    I::Item: Ord + Clone,
+{
+    non_empty: I,
+}
+
+impl<I: NonEmptyIterator> RunningMax<I>
+where
+    I::Item: Ord + Clone,
+{
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I) -> Self {
+        Self { non_empty }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for RunningMax<I>
+where
+    I::Item: Ord + Clone,
+{
+    type Item = I::Item;
+
+    type IntoIter = RunningMaxIter<I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RunningMaxIter::new(self.non_empty.into_iter())
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for RunningMax<I> where I::Item: Ord + Clone {}
+
+/// Represents iterators that yield the maximum item seen so far at each step.
+///
+/// This `struct` is created by the [`into_iter`] method on [`RunningMax`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RunningMaxIter<I: Iterator>
+where
+    I::Item: Ord + Clone,
+{
+    iterator: I,
+    max: Option<I::Item>,
+}
+
+impl<I: Iterator> RunningMaxIter<I>
+where
+    I::Item: Ord + Clone,
+{
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I) -> Self {
+        Self {
+            iterator,
+            max: None,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for RunningMaxIter<I>
+where
+    I::Item: Ord + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next()?;
+
+        let max = match self.max.take() {
+            Some(max) => max.max(item),
+            None => item,
+        };
+
+        self.max = Some(max.clone());
+
+        Some(max)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn running_min() {
+        let non_empty = [3, 1, 4, 1, 5].try_into_non_empty_iter().unwrap();
+
+        assert!(non_empty.running_min().into_iter().eq([3, 1, 1, 1, 1]));
+    }
+
+    #[test]
+    fn running_max() {
+        let non_empty = [3, 1, 4, 1, 5].try_into_non_empty_iter().unwrap();
+
+        assert!(non_empty.running_max().into_iter().eq([3, 3, 4, 4, 5]));
+    }
+}
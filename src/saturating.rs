@@ -0,0 +1,32 @@
+//! Saturating arithmetic for items of non-empty iterators.
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Represents types that support saturating addition, that is, the primitive integers.
+///
+/// This trait is sealed and can not be implemented outside of this crate.
+pub trait SaturatingAdd: sealed::Sealed + Sized {
+    /// Adds `other` to `self`, saturating at the numeric bounds instead of overflowing.
+    #[must_use]
+    fn saturating_add(self, other: Self) -> Self;
+}
+
+macro_rules! impl_saturating_add {
+    ($($type: ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $type {}
+
+            impl SaturatingAdd for $type {
+                fn saturating_add(self, other: Self) -> Self {
+                    <$type>::saturating_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_add!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
@@ -0,0 +1,90 @@
+//! Skipping the last provided number of items in non-empty iterators.
+
+use alloc::collections::VecDeque;
+
+/// Represents iterators that skip the last given number of items of the underlying iterator.
+///
+/// This `struct` is created by the [`skip_last`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`skip_last`]: crate::non_empty::NonEmptyIterator::skip_last
+/// [`NonEmptyIterator`]: crate::non_empty::NonEmptyIterator
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SkipLast<I: Iterator> {
+    iterator: I,
+    count: usize,
+    buffer: VecDeque<I::Item>,
+}
+
+impl<I: Iterator> SkipLast<I> {
+    /// Constructs [`Self`].
+    pub const fn new(iterator: I, count: usize) -> Self {
+        Self {
+            iterator,
+            count,
+            buffer: VecDeque::new(),
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for SkipLast<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // keep the last `count` items buffered, yielding only the ones that precede them
+        while self.buffer.len() < self.count {
+            let item = self.iterator.next()?;
+
+            self.buffer.push_back(item);
+        }
+
+        let item = self.iterator.next()?;
+
+        self.buffer.push_back(item);
+
+        self.buffer.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iterator.size_hint();
+
+        let buffered = self.buffer.len();
+
+        let lower = lower.saturating_add(buffered).saturating_sub(self.count);
+
+        let upper = upper.and_then(|upper| {
+            upper
+                .checked_add(buffered)
+                .map(|total| total.saturating_sub(self.count))
+        });
+
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn skip_last() {
+        let non_empty = [1, 2, 3, 4, 5].try_into_non_empty_iter().unwrap();
+
+        let skipped = non_empty.skip_last(Size::new(2).unwrap());
+
+        assert_eq!(skipped.size_hint(), (3, Some(3)));
+        assert!(skipped.eq([1, 2, 3]));
+    }
+
+    #[test]
+    fn skip_last_all() {
+        let non_empty = [1, 2, 3, 4, 5].try_into_non_empty_iter().unwrap();
+
+        let mut skipped = non_empty.skip_last(Size::new(5).unwrap());
+
+        assert_eq!(skipped.next(), None);
+    }
+}
@@ -0,0 +1,146 @@
+//! Viewing non-empty iterators as non-empty slices.
+
+use core::slice;
+
+use non_zero_size::Size;
+
+use crate::{adapter::NonEmptyAdapter, non_empty::NonEmptyIterator};
+
+/// Represents non-empty iterators backed by slices, allowing to view the remaining items
+/// as non-empty slices without collecting them.
+///
+/// # Safety
+///
+/// By implementing [`AsNonEmptySlice`] the implementor is responsible
+/// for ensuring that the returned slices are non-empty. Violating this invariant causes
+/// *Undefined Behavior*!
+pub unsafe trait AsNonEmptySlice: NonEmptyIterator {
+    /// The type of the elements of the slice.
+    type Element;
+
+    /// Returns the non-empty slice of the remaining items.
+    fn as_non_empty_slice(&self) -> &[Self::Element];
+
+    /// Returns the reference to the maximum element of the non-empty slice.
+    ///
+    /// This is similar to [`max`], but does not consume the non-empty iterator,
+    /// which avoids moving the items out of it.
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    ///
+    /// [`max`]: NonEmptyIterator::max
+    #[must_use]
+    fn max_ref(&self) -> &Self::Element
+    where
+        Self::Element: Ord,
+    {
+        let max = self.as_non_empty_slice().iter().max();
+
+        // SAFETY: the implementor guarantees the slice is non-empty
+        // therefore, `max` has to contain some value
+        unsafe { max.unwrap_unchecked() }
+    }
+
+    /// Returns the reference to the minimum element of the non-empty slice.
+    ///
+    /// This is similar to [`min`], but does not consume the non-empty iterator,
+    /// which avoids moving the items out of it.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    ///
+    /// [`min`]: NonEmptyIterator::min
+    #[must_use]
+    fn min_ref(&self) -> &Self::Element
+    where
+        Self::Element: Ord,
+    {
+        let min = self.as_non_empty_slice().iter().min();
+
+        // SAFETY: the implementor guarantees the slice is non-empty
+        // therefore, `min` has to contain some value
+        unsafe { min.unwrap_unchecked() }
+    }
+
+    /// Returns the non-empty iterator over overlapping windows of the given size
+    /// of the non-empty slice, borrowing the elements without copying them.
+    ///
+    /// See also [`windows`] on slices.
+    ///
+    /// Returns [`None`] if the size exceeds the length of the slice, as there are no windows.
+    ///
+    /// # Non-empty
+    ///
+    /// The returned iterator, if any, is guaranteed to be non-empty,
+    /// and each window is non-empty as well, since the size is non-zero.
+    ///
+    /// [`windows`]: slice::windows
+    fn slice_windows(
+        &self,
+        size: Size,
+    ) -> Option<NonEmptyAdapter<slice::Windows<'_, Self::Element>>> {
+        let slice = self.as_non_empty_slice();
+
+        // SAFETY: the size does not exceed the length of the slice,
+        // therefore there is at least one window
+        (size.get() <= slice.len())
+            .then(|| unsafe { NonEmptyAdapter::new(slice.windows(size.get())) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::string::String;
+
+    use non_zero_size::Size;
+
+    use super::AsNonEmptySlice;
+    use crate::{adapter::NonEmptyAdapter, non_empty::NonEmptyIterator};
+
+    #[test]
+    fn as_non_empty_slice() {
+        let array = [1, 2, 3];
+
+        // SAFETY: `array` is non-empty
+        let non_empty = unsafe { NonEmptyAdapter::new(array.iter()) };
+
+        assert_eq!(non_empty.as_non_empty_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn max_min_ref() {
+        let strings = [String::from("b"), String::from("c"), String::from("a")];
+
+        // SAFETY: `strings` is non-empty
+        let non_empty = unsafe { NonEmptyAdapter::new(strings.iter()) };
+
+        assert_eq!(non_empty.max_ref(), "c");
+        assert_eq!(non_empty.min_ref(), "a");
+    }
+
+    #[test]
+    fn slice_windows() {
+        let array = [1, 2, 3];
+
+        // SAFETY: `array` is non-empty
+        let non_empty = unsafe { NonEmptyAdapter::new(array.as_slice()) };
+
+        let windows = non_empty.slice_windows(Size::new(2).unwrap()).unwrap();
+
+        assert!(windows.eq([[1, 2].as_slice(), [2, 3].as_slice()]));
+
+        assert!(non_empty.slice_windows(Size::new(4).unwrap()).is_none());
+    }
+
+    #[test]
+    fn as_non_empty_slice_array() {
+        // SAFETY: the array is non-empty
+        let non_empty = unsafe { NonEmptyAdapter::new([1, 2, 3].into_iter()) };
+
+        let remaining = non_empty.as_non_empty_slice();
+
+        assert!(!remaining.is_empty());
+        assert_eq!(remaining, &[1, 2, 3]);
+    }
+}
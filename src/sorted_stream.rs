@@ -0,0 +1,95 @@
+//! Streaming items of non-empty iterators in ascending order.
+
+use core::cmp::Reverse;
+
+use alloc::collections::BinaryHeap;
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that yield the items in ascending order.
+///
+/// This `struct` is created by the [`sorted_stream`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`sorted_stream`]: NonEmptyIterator::sorted_stream
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct SortedStream<I: NonEmptyIterator>
+where
+    I::Item: Ord,
+{
+    non_empty: I,
+}
+
+impl<I: NonEmptyIterator> SortedStream<I>
+where
+    I::Item: Ord,
+{
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I) -> Self {
+        Self { non_empty }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for SortedStream<I>
+where
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    type IntoIter = SortedStreamIter<I::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SortedStreamIter::new(self.non_empty.into_iter().map(Reverse).collect())
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for SortedStream<I> where I::Item: Ord {}
+
+/// Represents iterators that yield the items in ascending order.
+///
+/// This `struct` is created by the [`into_iter`] method on [`SortedStream`].
+///
+/// The items are kept in the binary heap, and each one is popped when it is requested.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SortedStreamIter<T: Ord> {
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> SortedStreamIter<T> {
+    /// Constructs [`Self`].
+    pub const fn new(heap: BinaryHeap<Reverse<T>>) -> Self {
+        Self { heap }
+    }
+}
+
+impl<T: Ord> Iterator for SortedStreamIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.heap.pop().map(|Reverse(item)| item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.heap.len();
+
+        (len, Some(len))
+    }
+}
+
+impl<T: Ord> ExactSizeIterator for SortedStreamIter<T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn sorted_stream_ascending() {
+        let non_empty = [3, 1, 2].try_into_non_empty_iter().unwrap();
+
+        assert!(non_empty.sorted_stream().eq([1, 2, 3]));
+    }
+}
@@ -4,7 +4,7 @@ use core::iter;
 
 use non_zero_size::Size;
 
-use crate::non_empty::NonEmptyIterator;
+use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator};
 
 /// Represents non-empty iterators that only iterate over the first given number of items
 /// of the underlying iterator.
@@ -33,3 +33,31 @@ impl<I: NonEmptyIterator> IntoIterator for Take<I> {
 }
 
 unsafe impl<I: NonEmptyIterator> NonEmptyIterator for Take<I> {}
+
+impl<I: ExactSizeNonEmptyIterator> ExactSizeNonEmptyIterator for Take<I> {
+    fn len(&self) -> Size {
+        self.non_empty.len().min(self.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::non_empty::{ExactSizeNonEmptyIterator, NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn take_len() {
+        let non_empty = [1, 2, 3, 4, 5].try_into_non_empty_iter().unwrap();
+
+        assert_eq!(
+            non_empty.clone().take(Size::new(3).unwrap()).len(),
+            Size::new(3).unwrap()
+        );
+
+        assert_eq!(
+            non_empty.take(Size::new(10).unwrap()).len(),
+            Size::new(5).unwrap()
+        );
+    }
+}
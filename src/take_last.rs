@@ -0,0 +1,78 @@
+//! Iterating over only the last provided number of items in non-empty iterators.
+
+use alloc::collections::{VecDeque, vec_deque};
+
+use non_zero_size::Size;
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Represents non-empty iterators that only iterate over the last given number of items
+/// of the underlying iterator.
+///
+/// This `struct` is created by the [`take_last`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`take_last`]: NonEmptyIterator::take_last
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct TakeLast<I: NonEmptyIterator> {
+    non_empty: I,
+    count: Size,
+}
+
+impl<I: NonEmptyIterator> TakeLast<I> {
+    /// Constructs [`Self`].
+    pub const fn new(non_empty: I, count: Size) -> Self {
+        Self { non_empty, count }
+    }
+}
+
+impl<I: NonEmptyIterator> IntoIterator for TakeLast<I> {
+    type Item = I::Item;
+
+    type IntoIter = vec_deque::IntoIter<I::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let count = self.count.get();
+
+        let mut buffer = VecDeque::new();
+
+        for item in self.non_empty {
+            // keep only the most recent `count` items
+            if buffer.len() == count {
+                buffer.pop_front();
+            }
+
+            buffer.push_back(item);
+        }
+
+        buffer.into_iter()
+    }
+}
+
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for TakeLast<I> {}
+
+#[cfg(test)]
+mod tests {
+    use non_zero_size::Size;
+
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn take_last() {
+        let non_empty = [1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        let taken = non_empty.take_last(Size::new(2).unwrap()).into_iter();
+
+        assert!(taken.eq([3, 4]));
+    }
+
+    #[test]
+    fn take_last_shorter() {
+        let non_empty = [1, 2].try_into_non_empty_iter().unwrap();
+
+        let taken = non_empty.take_last(Size::new(3).unwrap()).into_iter();
+
+        assert!(taken.eq([1, 2]));
+    }
+}
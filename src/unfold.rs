@@ -0,0 +1,110 @@
+//! Non-empty iterators that yield the first item and then compute items from the state.
+
+use crate::non_empty::NonEmptyIterator;
+
+/// Creates [`Unfold<S, T, F>`] non-empty iterator that yields the first item
+/// and then items computed from the state until the function returns [`None`].
+pub const fn unfold<S, T, F: FnMut(&mut S) -> Option<T>>(
+    first: T,
+    state: S,
+    function: F,
+) -> Unfold<S, T, F> {
+    Unfold::new(first, state, function)
+}
+
+/// Represents non-empty iterators that yield the first item
+/// and then items computed from the state until the function returns [`None`].
+///
+/// This `struct` is created by the [`unfold`] function. See its documentation for more.
+#[derive(Debug, Clone)]
+#[must_use = "non-empty iterators are lazy and do nothing unless consumed"]
+pub struct Unfold<S, T, F: FnMut(&mut S) -> Option<T>> {
+    first: T,
+    state: S,
+    function: F,
+}
+
+impl<S, T, F: FnMut(&mut S) -> Option<T>> Unfold<S, T, F> {
+    /// Constructs [`Self`].
+    pub const fn new(first: T, state: S, function: F) -> Self {
+        Self {
+            first,
+            state,
+            function,
+        }
+    }
+}
+
+impl<S, T, F: FnMut(&mut S) -> Option<T>> IntoIterator for Unfold<S, T, F> {
+    type Item = T;
+
+    type IntoIter = UnfoldIter<S, T, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        UnfoldIter::new(Some(self.first), self.state, self.function)
+    }
+}
+
+unsafe impl<S, T, F: FnMut(&mut S) -> Option<T>> NonEmptyIterator for Unfold<S, T, F> {}
+
+/// Represents iterators that yield the first item, if any,
+/// and then items computed from the state until the function returns [`None`].
+///
+/// This `struct` is created by the [`into_iter`] method on [`Unfold`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct UnfoldIter<S, T, F: FnMut(&mut S) -> Option<T>> {
+    first: Option<T>,
+    state: S,
+    function: F,
+}
+
+impl<S, T, F: FnMut(&mut S) -> Option<T>> UnfoldIter<S, T, F> {
+    /// Constructs [`Self`].
+    pub const fn new(first: Option<T>, state: S, function: F) -> Self {
+        Self {
+            first,
+            state,
+            function,
+        }
+    }
+}
+
+impl<S, T, F: FnMut(&mut S) -> Option<T>> Iterator for UnfoldIter<S, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.first
+            .take()
+            .or_else(|| (self.function)(&mut self.state))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(self.first.is_some()), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unfold;
+    use crate::non_empty::NonEmptyIterator;
+
+    #[test]
+    fn unfold_fibonacci() {
+        let fibonacci = unfold(0, (0, 1), |(previous, current)| {
+            if *current > 10 {
+                return None;
+            }
+
+            let next = *previous + *current;
+
+            *previous = *current;
+
+            Some(core::mem::replace(current, next))
+        });
+
+        assert!(fibonacci.eq([0, 1, 1, 2, 3, 5, 8]));
+    }
+}
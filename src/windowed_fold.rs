@@ -0,0 +1,118 @@
+//! Folding overlapping fixed-size windows of non-empty iterators.
+
+use core::array;
+
+/// Represents iterators that fold each window of `N` consecutive items into the value.
+///
+/// This `struct` is created by the [`windowed_fold`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`windowed_fold`]: crate::non_empty::NonEmptyIterator::windowed_fold
+/// [`NonEmptyIterator`]: crate::non_empty::NonEmptyIterator
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WindowedFold<I: Iterator, const N: usize, A: Clone, F: FnMut(A, &[I::Item; N]) -> A> {
+    iterator: I,
+    window: Option<[I::Item; N]>,
+    initial: A,
+    function: F,
+}
+
+impl<I: Iterator, const N: usize, A: Clone, F: FnMut(A, &[I::Item; N]) -> A>
+    WindowedFold<I, N, A, F>
+{
+    /// Constructs [`Self`].
+    ///
+    /// Note that `N` must be non-zero, otherwise the code fails to compile.
+    pub const fn new(iterator: I, initial: A, function: F) -> Self {
+        const { assert!(N > 0, "window size must be non-zero") };
+
+        Self {
+            iterator,
+            window: None,
+            initial,
+            function,
+        }
+    }
+}
+
+impl<I: Iterator, const N: usize, A: Clone, F: FnMut(A, &[I::Item; N]) -> A> Iterator
+    for WindowedFold<I, N, A, F>
+{
+    type Item = A;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = advance(&mut self.iterator, &mut self.window)?;
+
+        Some((self.function)(self.initial.clone(), window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iterator.size_hint();
+
+        if self.window.is_some() {
+            return (lower, upper);
+        }
+
+        let lower = lower.saturating_add(1).saturating_sub(N);
+        let upper = upper
+            .and_then(|upper| upper.checked_add(1))
+            .map(|upper| upper.saturating_sub(N));
+
+        (lower, upper)
+    }
+}
+
+/// Moves the window forward by one item, filling it completely first if needed.
+fn advance<'w, I: Iterator, const N: usize>(
+    iterator: &mut I,
+    window: &'w mut Option<[I::Item; N]>,
+) -> Option<&'w [I::Item; N]> {
+    match window {
+        Some(window) => {
+            let item = iterator.next()?;
+
+            window.rotate_left(1);
+            window[N - 1] = item;
+
+            Some(window)
+        }
+        None => {
+            let mut slots: [Option<I::Item>; N] = array::from_fn(|_| None);
+
+            for slot in &mut slots {
+                *slot = Some(iterator.next()?);
+            }
+
+            // SAFETY: all slots are filled, as we would have returned otherwise
+            let filled = slots.map(|slot| unsafe { slot.unwrap_unchecked() });
+
+            Some(window.insert(filled))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+    #[test]
+    fn windowed_fold_sum() {
+        let non_empty = [1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        let sums = non_empty
+            .windowed_fold::<2, _, _>(0, |total, window| total + window.iter().sum::<i32>());
+
+        assert!(sums.eq([3, 5, 7]));
+    }
+
+    #[test]
+    fn windowed_fold_short() {
+        let non_empty = [1, 2].try_into_non_empty_iter().unwrap();
+
+        let mut sums = non_empty
+            .windowed_fold::<3, _, _>(0, |total, window| total + window.iter().sum::<i32>());
+
+        assert_eq!(sums.next(), None);
+    }
+}
@@ -0,0 +1,156 @@
+//! Overlapping fixed-size windows over non-empty iterators.
+
+use core::array;
+
+use alloc::collections::VecDeque;
+
+/// Represents iterators that yield overlapping windows of `N` consecutive items as arrays.
+///
+/// This `struct` is created by the [`windows_exact`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// # Double-ended
+///
+/// If the underlying iterator is [`DoubleEndedIterator`], so is this one, and windows can be
+/// yielded from both ends in any order, with each window being yielded exactly once.
+///
+/// [`windows_exact`]: crate::non_empty::NonEmptyIterator::windows_exact
+/// [`NonEmptyIterator`]: crate::non_empty::NonEmptyIterator
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WindowsExact<I: Iterator, const N: usize>
+where
+    I::Item: Clone,
+{
+    iterator: I,
+    front: VecDeque<I::Item>,
+    back: VecDeque<I::Item>,
+}
+
+impl<I: Iterator, const N: usize> WindowsExact<I, N>
+where
+    I::Item: Clone,
+{
+    /// Constructs [`Self`].
+    ///
+    /// Note that `N` must be non-zero, otherwise the code fails to compile.
+    pub const fn new(iterator: I) -> Self {
+        const { assert!(N > 0, "window size must be non-zero") };
+
+        Self {
+            iterator,
+            front: VecDeque::new(),
+            back: VecDeque::new(),
+        }
+    }
+
+    fn windows(&self, items: usize) -> Option<usize> {
+        let items = items
+            .checked_add(self.front.len())?
+            .checked_add(self.back.len())?;
+
+        Some(items.saturating_sub(N - 1))
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for WindowsExact<I, N>
+where
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the items taken from the back buffer are the ones following the front buffer
+        while self.front.len() < N {
+            let item = match self.iterator.next() {
+                Some(item) => item,
+                None => self.back.pop_front()?,
+            };
+
+            self.front.push_back(item);
+        }
+
+        let window = array::from_fn(|index| self.front[index].clone());
+
+        self.front.pop_front();
+
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iterator.size_hint();
+
+        let lower = self.windows(lower).unwrap_or(usize::MAX);
+        let upper = upper.and_then(|upper| self.windows(upper));
+
+        (lower, upper)
+    }
+}
+
+impl<I: DoubleEndedIterator, const N: usize> DoubleEndedIterator for WindowsExact<I, N>
+where
+    I::Item: Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // the items taken from the front buffer are the ones preceding the back buffer
+        while self.back.len() < N {
+            let item = match self.iterator.next_back() {
+                Some(item) => item,
+                None => self.front.pop_back()?,
+            };
+
+            self.back.push_front(item);
+        }
+
+        let window = array::from_fn(|index| self.back[index].clone());
+
+        self.back.pop_back();
+
+        Some(window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{
+        non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator},
+        repeat::repeat,
+    };
+
+    #[test]
+    fn windows_exact_mirrored() {
+        let non_empty = [1, 2, 3, 4, 5].try_into_non_empty_iter().unwrap();
+
+        let forward: Vec<_> = non_empty.clone().windows_exact::<3>().collect();
+
+        let mut backward: Vec<_> = non_empty.windows_exact::<3>().rev().collect();
+
+        backward.reverse();
+
+        assert_eq!(forward, [[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn windows_exact_both_ends() {
+        let non_empty = [1, 2, 3, 4].try_into_non_empty_iter().unwrap();
+
+        let mut windows = non_empty.windows_exact::<2>();
+
+        assert_eq!(windows.size_hint(), (3, Some(3)));
+        assert_eq!(windows.next(), Some([1, 2]));
+        assert_eq!(windows.next_back(), Some([3, 4]));
+        assert_eq!(windows.size_hint(), (1, Some(1)));
+        assert_eq!(windows.next(), Some([2, 3]));
+        assert_eq!(windows.next_back(), None);
+    }
+
+    #[test]
+    fn windows_exact_endless_size_hint() {
+        let windows = repeat(1u8).windows_exact::<2>();
+
+        assert_eq!(windows.size_hint(), (usize::MAX - 1, None));
+    }
+}
@@ -0,0 +1,72 @@
+//! Pairing items of non-empty iterators with their successors.
+
+use core::mem;
+
+/// Represents iterators that yield each item along with the following one.
+///
+/// This `struct` is created by the [`zip_with_next`] method on [`NonEmptyIterator`].
+/// See its documentation for more.
+///
+/// [`zip_with_next`]: crate::non_empty::NonEmptyIterator::zip_with_next
+/// [`NonEmptyIterator`]: crate::non_empty::NonEmptyIterator
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipWithNext<I: Iterator>
+where
+    I::Item: Clone,
+{
+    previous: I::Item,
+    iterator: I,
+}
+
+impl<I: Iterator> ZipWithNext<I>
+where
+    I::Item: Clone,
+{
+    /// Constructs [`Self`].
+    pub const fn new(first: I::Item, iterator: I) -> Self {
+        Self {
+            previous: first,
+            iterator,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for ZipWithNext<I>
+where
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.iterator.next()?;
+
+        let current = mem::replace(&mut self.previous, next.clone());
+
+        Some((current, next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        non_empty::{NonEmptyIterator, TryIntoNonEmptyIterator},
+        once::once,
+    };
+
+    #[test]
+    fn zip_with_next() {
+        let non_empty = [1, 2, 3].try_into_non_empty_iter().unwrap();
+
+        assert!(non_empty.zip_with_next().eq([(1, 2), (2, 3)]));
+    }
+
+    #[test]
+    fn zip_with_next_single() {
+        assert_eq!(once(1).zip_with_next().next(), None);
+    }
+}
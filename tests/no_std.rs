@@ -0,0 +1,12 @@
+//! Testing non-empty iterators without the standard library.
+
+#![no_std]
+
+use non_empty_iter::{NonEmptyIterator, TryIntoNonEmptyIterator};
+
+#[test]
+fn dedup_array() {
+    let non_empty = [1, 1, 2, 3, 3, 3, 1].try_into_non_empty_iter().unwrap();
+
+    assert!(non_empty.dedup().eq([1, 2, 3, 1]));
+}